@@ -0,0 +1,30 @@
+/// 24 bit RGB color
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Multiplies every channel, e.g. to darken a color by the light falling on a surface
+    ///
+    /// # Parameters
+    /// - `factor`: brightness from 0 to 1, values outside are clamped
+    pub fn scale(&self, factor: f64) -> Self {
+        let channel = |c: u8| (c as f64 * factor.clamp(0.0, 1.0)).round() as u8;
+
+        Self::new(channel(self.r), channel(self.g), channel(self.b))
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from(value: [u8; 3]) -> Self {
+        Self::new(value[0], value[1], value[2])
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+/// Single rasterized pixel of a triangle
+#[allow(dead_code)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Fragment {
+    /// Screen position of the pixel
+    pub position: Vec2,
+    /// Interpolated distance of the pixel from the camera
+    pub depth: f64,
+    /// Perspective correct barycentric weights of the triangle corners at this pixel
+    pub weights: [f64; 3],
+}
+
+impl Fragment {
+    pub fn new(position: Vec2, depth: f64, weights: [f64; 3]) -> Self {
+        Self { position, depth, weights }
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// Distance under which vertices are considered the same when converting a Mesh
+pub const DEFAULT_WELD_TOLERANCE: f64 = 1e-9;
+
+/// How faces sharing a vertex contribute to its smooth normal
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalWeighting {
+    /// Larger faces contribute more
+    #[default]
+    Area,
+    /// Faces contribute by the angle of their corner at the vertex, independent of tessellation
+    Angle,
+}
+
+/// Mesh stored as a vertex buffer and an index buffer, so vertices shared by
+/// multiple triangles are stored and transformed only once.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let indexed = IndexedMesh::from(&MESHES::cube(2.0));
+///
+/// assert_eq!(indexed.vertices.len(), 8);
+/// assert_eq!(indexed.indices.len(), 12);
+///
+/// let mesh: Mesh = indexed.into();
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedMesh {
+    /// Unique vertex positions
+    pub vertices: Vec<Vec3>,
+    /// Normal of each vertex, present when every source triangle had normals
+    pub normals: Option<Vec<Vec3>>,
+    /// Texture coordinates of each vertex, present when every source triangle had them
+    pub uvs: Option<Vec<Vec2>>,
+    /// Color of each vertex, present when every source triangle had colors
+    pub colors: Option<Vec<Color>>,
+    /// Triangles as indices into the vertex buffer
+    pub indices: Vec<[usize; 3]>,
+    /// Position of the Mesh represented by a 3D Vector
+    pub position: Vec3,
+    /// XYZ rotation of the Mesh
+    pub rotation: Rotation,
+    /// Surface of the Mesh
+    pub material: Material,
+}
+
+/// Key of a grid cell used to find vertices close to each other
+type Cell = (i64, i64, i64);
+
+impl IndexedMesh {
+    /// Converts a Mesh, merging vertices closer than `tolerance` which also share all attributes
+    ///
+    /// # Parameters
+    /// - `mesh`: Mesh to be converted
+    /// - `tolerance`: maximal distance of merged positions, normals and texture coordinates
+    pub fn from_mesh(mesh: &Mesh, tolerance: f64) -> Self {
+        let has_normals = !mesh.triangles.is_empty() && mesh.triangles.iter().all(|t| t.normals.is_some());
+        let has_uvs = !mesh.triangles.is_empty() && mesh.triangles.iter().all(|t| t.uvs.is_some());
+        let has_colors = !mesh.triangles.is_empty() && mesh.triangles.iter().all(|t| t.colors.is_some());
+
+        let mut result = Self {
+            vertices: vec![],
+            normals: has_normals.then(Vec::new),
+            uvs: has_uvs.then(Vec::new),
+            colors: has_colors.then(Vec::new),
+            indices: Vec::with_capacity(mesh.triangles.len()),
+            position: mesh.position,
+            rotation: mesh.rotation.clone(),
+            material: mesh.material.clone(),
+        };
+
+        // Equal positions share a cell of any size, so an exact weld uses unit cells
+        let cell_size = if tolerance > 0.0 { tolerance } else { 1.0 };
+        let cell_of = |v: Vec3| -> Cell {
+            (
+                (v.x / cell_size).floor() as i64,
+                (v.y / cell_size).floor() as i64,
+                (v.z / cell_size).floor() as i64,
+            )
+        };
+        let mut grid: HashMap<Cell, Vec<usize>> = HashMap::new();
+
+        for t in &mesh.triangles {
+            let mut triangle = [0; 3];
+
+            for (corner, index) in triangle.iter_mut().enumerate() {
+                let position = t.vertices[corner];
+                let normal = t.normals.map(|n| n[corner]).filter(|_| has_normals);
+                let uv = t.uvs.map(|u| u[corner]).filter(|_| has_uvs);
+                let color = t.colors.map(|c| c[corner]).filter(|_| has_colors);
+
+                let (cx, cy, cz) = cell_of(position);
+                let existing = (-1..=1)
+                    .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| {
+                        // Far away positions saturate into the outermost cells
+                        (cx.saturating_add(dx), cy.saturating_add(dy), cz.saturating_add(dz))
+                    })))
+                    .filter_map(|cell| grid.get(&cell))
+                    .flatten()
+                    .copied()
+                    .find(|&i| {
+                        result.vertices[i].distance(position) <= tolerance
+                            && normal.is_none_or(|n| result.normals.as_ref().unwrap()[i].distance(n) <= tolerance)
+                            && uv.is_none_or(|u| result.uvs.as_ref().unwrap()[i].distance(u) <= tolerance)
+                            && color.is_none_or(|c| result.colors.as_ref().unwrap()[i] == c)
+                    });
+
+                *index = match existing {
+                    Some(i) => i,
+                    None => {
+                        let i = result.vertices.len();
+                        result.vertices.push(position);
+                        if let (Some(normals), Some(n)) = (result.normals.as_mut(), normal) {
+                            normals.push(n);
+                        }
+                        if let (Some(uvs), Some(u)) = (result.uvs.as_mut(), uv) {
+                            uvs.push(u);
+                        }
+                        if let (Some(colors), Some(c)) = (result.colors.as_mut(), color) {
+                            colors.push(c);
+                        }
+                        grid.entry((cx, cy, cz)).or_default().push(i);
+                        i
+                    }
+                };
+            }
+
+            result.indices.push(triangle);
+        }
+
+        result
+    }
+
+    /// Calculates a smooth normal of every vertex by averaging normals of faces sharing it
+    ///
+    /// # Parameters
+    /// - `weighting`: how much each face contributes
+    ///
+    /// # Returns
+    /// unit normal of each vertex, zero for vertices used only by degenerate faces
+    pub fn vertex_normals(&self, weighting: NormalWeighting) -> Vec<Vec3> {
+        let mut normals = vec![Vec3::new(0.0, 0.0, 0.0); self.vertices.len()];
+
+        for &[a, b, c] in &self.indices {
+            let corners = [self.vertices[a], self.vertices[b], self.vertices[c]];
+            // Length of the cross product is twice the area of the face
+            let face = (corners[1] - corners[0]).cross(corners[2] - corners[0]);
+
+            for (corner, &index) in [a, b, c].iter().enumerate() {
+                normals[index] += match weighting {
+                    NormalWeighting::Area => face,
+                    NormalWeighting::Angle => {
+                        let to_next = corners[(corner + 1) % 3] - corners[corner];
+                        let to_previous = corners[(corner + 2) % 3] - corners[corner];
+                        let angle = match (to_next.try_normalize(), to_previous.try_normalize()) {
+                            (Some(n), Some(p)) => n.dot(p).clamp(-1.0, 1.0).acos(),
+                            _ => 0.0,
+                        };
+
+                        face.try_normalize().unwrap_or(face) * angle
+                    }
+                };
+            }
+        }
+
+        normals.into_iter().map(|n| n.try_normalize().unwrap_or(n)).collect()
+    }
+
+    /// Replaces normals of the mesh by smooth normals calculated from its faces
+    ///
+    /// # Parameters
+    /// - `weighting`: how much each face contributes
+    pub fn compute_normals(&mut self, weighting: NormalWeighting) {
+        self.normals = Some(self.vertex_normals(weighting));
+    }
+
+    /// Builds a standalone triangle from the index buffer
+    ///
+    /// # Parameters
+    /// - `i`: index of the triangle
+    pub fn triangle(&self, i: usize) -> Triangle {
+        let [a, b, c] = self.indices[i];
+        let mut triangle = Triangle::new([self.vertices[a], self.vertices[b], self.vertices[c]]);
+
+        if let Some(normals) = &self.normals {
+            triangle = triangle.with_normals([normals[a], normals[b], normals[c]]);
+        }
+        if let Some(uvs) = &self.uvs {
+            triangle = triangle.with_uvs([uvs[a], uvs[b], uvs[c]]);
+        }
+        if let Some(colors) = &self.colors {
+            triangle = triangle.with_colors([colors[a], colors[b], colors[c]]);
+        }
+
+        triangle
+    }
+
+    /// Converts back into a Mesh storing full triangles
+    pub fn to_mesh(&self) -> Mesh {
+        Mesh::new()
+            .set_triangles((0..self.indices.len()).map(|i| self.triangle(i)).collect())
+            .set_position(self.position)
+            .set_rotation(self.rotation.x, self.rotation.y, self.rotation.z)
+            .set_material(self.material.clone())
+            .build()
+    }
+
+    /// Builds the matrix moving the Mesh from its own object space into world space.
+    pub fn world_matrix(&self) -> Matrix<f64, 4, 4> {
+        model_matrix_3d(&self.rotation, self.position)
+    }
+}
+
+impl From<&Mesh> for IndexedMesh {
+    fn from(value: &Mesh) -> Self {
+        IndexedMesh::from_mesh(value, DEFAULT_WELD_TOLERANCE)
+    }
+}
+
+impl From<Mesh> for IndexedMesh {
+    fn from(value: Mesh) -> Self {
+        IndexedMesh::from_mesh(&value, DEFAULT_WELD_TOLERANCE)
+    }
+}
+
+impl From<&IndexedMesh> for Mesh {
+    fn from(value: &IndexedMesh) -> Self {
+        value.to_mesh()
+    }
+}
+
+impl From<IndexedMesh> for Mesh {
+    fn from(value: IndexedMesh) -> Self {
+        value.to_mesh()
+    }
+}
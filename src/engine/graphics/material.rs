@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// Describes how the surface of a Mesh reacts to light and which chars draw it
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let metal = Material::new()
+///     .with_specular(0.8)
+///     .with_shininess(64.0)
+///     .with_ramp(" .oO0@")
+///     .with_color(Color::new(180, 180, 200));
+///
+/// let marker = Material::new().with_character('X');
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Material {
+    /// Portion of ambient light reflected
+    pub ambient: f64,
+    /// Portion of diffuse light reflected
+    pub diffuse: f64,
+    /// Strength of specular highlights, zero gives a matte surface
+    pub specular: f64,
+    /// Exponent of specular highlights, larger values give smaller highlights
+    pub shininess: f64,
+    /// Char drawn regardless of the light
+    pub character: Option<char>,
+    /// Chars ordered from the darkest to the brightest, replaces `ASCII_BRIGHTNESS`
+    pub ramp: Option<Vec<char>>,
+    /// Color of the surface
+    pub color: Option<Color>,
+}
+
+impl Material {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the portion of ambient light reflected
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_ambient(mut self, ambient: f64) -> Self {
+        self.ambient = ambient;
+
+        self
+    }
+
+    /// Sets the portion of diffuse light reflected
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_diffuse(mut self, diffuse: f64) -> Self {
+        self.diffuse = diffuse;
+
+        self
+    }
+
+    /// Sets the strength of specular highlights
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_specular(mut self, specular: f64) -> Self {
+        self.specular = specular;
+
+        self
+    }
+
+    /// Sets the exponent of specular highlights
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_shininess(mut self, shininess: f64) -> Self {
+        self.shininess = shininess;
+
+        self
+    }
+
+    /// Sets a char drawn regardless of the light
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_character(mut self, character: char) -> Self {
+        self.character = Some(character);
+
+        self
+    }
+
+    /// Sets chars used for each brightness level, from the darkest to the brightest
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_ramp(mut self, ramp: &str) -> Self {
+        self.ramp = Some(ramp.chars().collect());
+
+        self
+    }
+
+    /// Sets the color of the surface
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    /// Brightness of the surface lit by `illumination`
+    pub fn intensity(&self, illumination: &Illumination) -> f64 {
+        self.ambient * illumination.ambient + self.diffuse * illumination.diffuse + self.specular * illumination.specular
+    }
+
+    /// Char representing a brightness of the surface
+    ///
+    /// # Parameters
+    /// - `intensity`: brightness from 0 to 1, values outside are clamped
+    pub fn ascii(&self, intensity: f64) -> char {
+        match (self.character, &self.ramp) {
+            (Some(c), _) => c,
+            (None, Some(ramp)) if !ramp.is_empty() => {
+                ramp[(intensity.clamp(0.0, 1.0) * (ramp.len() - 1) as f64).round() as usize]
+            }
+            _ => ascii_from_intensity(intensity),
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            ambient: 1.0,
+            diffuse: 1.0,
+            specular: 0.5,
+            shininess: 32.0,
+            character: None,
+            ramp: None,
+            color: None,
+        }
+    }
+}
@@ -27,6 +27,8 @@ pub struct Mesh {
     pub position: Vec3,
     /// XYZ rotation of a Mesh
     pub rotation: Rotation,
+    /// Surface of a Mesh
+    pub material: Material,
 }
 
 impl Mesh {
@@ -37,8 +39,50 @@ impl Mesh {
             triangles: vec![],
             position: None,
             rotation: None,
+            material: None,
         }
     }
+
+    /// Builds the matrix moving the Mesh from its own object space into world space.
+    ///
+    /// # Returns
+    /// homogeneous matrix applying the Mesh rotation followed by its position
+    pub fn world_matrix(&self) -> Matrix<f64, 4, 4> {
+        model_matrix_3d(&self.rotation, self.position)
+    }
+
+    /// Replaces corner normals of every triangle by smooth normals.
+    /// Corners at the same position share a normal averaged from all faces touching it.
+    ///
+    /// # Parameters
+    /// - `weighting`: how much each face contributes
+    pub fn compute_normals(&mut self, weighting: NormalWeighting) {
+        let positions = Mesh {
+            triangles: self.triangles.iter().map(|t| Triangle::new(t.vertices)).collect(),
+            position: self.position,
+            rotation: self.rotation.clone(),
+            material: Material::default(),
+        };
+        let indexed = IndexedMesh::from(&positions);
+        let normals = indexed.vertex_normals(weighting);
+
+        self.triangles.iter_mut().zip(&indexed.indices).for_each(|(t, &[a, b, c])| {
+            t.normals = Some([normals[a], normals[b], normals[c]]);
+        });
+    }
+
+    /// Orientation of the Mesh as a quaternion
+    pub fn orientation(&self) -> Quaternion {
+        Quaternion::from_rotation(&self.rotation, EulerOrder::XYZ)
+    }
+
+    /// Sets orientation of the Mesh from a quaternion
+    ///
+    /// # Parameters
+    /// -`orientation`: new orientation, e.g. composed or interpolated from other quaternions
+    pub fn set_orientation(&mut self, orientation: Quaternion) {
+        self.rotation = orientation.to_rotation(EulerOrder::XYZ);
+    }
 }
 
 /// Struct used in a process of building a Mesh.
@@ -48,6 +92,7 @@ pub struct MeshBuilder {
     pub triangles: Vec<Triangle>,
     pub position: Option<Vec3>,
     pub rotation: Option<Rotation>,
+    pub material: Option<Material>,
 }
 
 impl MeshBuilder {
@@ -59,8 +104,22 @@ impl MeshBuilder {
         let triangles = self.triangles;
         let position = self.position.unwrap_or([0.0, 0.0, 0.0].into());
         let rotation = self.rotation.unwrap_or(Rotation::new(0.0, 0.0, 0.0));
+        let material = self.material.unwrap_or_default();
+
+        Mesh { triangles, position, rotation, material }
+    }
 
-        Mesh { triangles, position, rotation }
+    /// Sets the surface of a Mesh
+    ///
+    /// # Parameters
+    /// -`material`: how the Mesh reacts to light and which chars draw it
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn set_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+
+        self
     }
 
     /// Sets triangle array on Mesh
@@ -106,6 +165,20 @@ impl MeshBuilder {
         self
     }
 
+    /// Sets rotation from a quaternion
+    ///
+    /// # Parameters
+    /// -`orientation`: orientation of the Mesh
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    ///
+    pub fn set_orientation(mut self, orientation: Quaternion) -> Self {
+        self.rotation = Some(orientation.to_rotation(EulerOrder::XYZ));
+
+        self
+    }
+
     /// Sets rotation on X axis
     ///
     /// # Parameters
@@ -1,4 +1,23 @@
+use std::{
+    collections::HashMap,
+    f64::consts::{FRAC_PI_4, PI, TAU},
+};
+
 use crate::prelude::*;
+
+/// Generators of primitive Meshes.
+///
+/// Every primitive is centered at the origin and its triangles are wound counter clockwise
+/// when looking at them from the outside, so their normals point outwards.
+/// Round primitives are built around the Y axis.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let sphere = MESHES::icosphere(1.0, 2);
+/// let donut = MESHES::torus(2.0, 0.5, 24, 12);
+/// ```
 #[allow(dead_code)]
 pub struct MESHES;
 
@@ -18,29 +37,280 @@ impl MESHES {
         ];
 
         let triangles = vec![
-            Triangle::from([v[0], v[1], v[2]]),
-            Triangle::from([v[0], v[2], v[3]]),
+            Triangle::from([v[0], v[2], v[1]]),
+            Triangle::from([v[0], v[3], v[2]]),
 
-            Triangle::from([ v[5], v[4], v[7] ]),
-            Triangle::from([ v[5], v[7], v[6] ]),
+            Triangle::from([v[5], v[7], v[4]]),
+            Triangle::from([v[5], v[6], v[7]]),
 
-            Triangle::from([ v[4], v[0], v[3] ]),
-            Triangle::from([ v[4], v[3], v[7] ]),
+            Triangle::from([v[4], v[3], v[0]]),
+            Triangle::from([v[4], v[7], v[3]]),
 
-            Triangle::from([ v[1], v[5], v[6] ]),
-            Triangle::from([ v[1], v[6], v[2] ]),
+            Triangle::from([v[1], v[6], v[5]]),
+            Triangle::from([v[1], v[2], v[6]]),
 
-            Triangle::from([ v[4], v[5], v[1] ]),
-            Triangle::from([ v[4], v[1], v[0] ]),
+            Triangle::from([v[4], v[1], v[5]]),
+            Triangle::from([v[4], v[0], v[1]]),
 
-            Triangle::from([ v[3], v[2], v[6] ]),
-            Triangle::from([ v[3], v[6], v[7] ]),
+            Triangle::from([v[3], v[6], v[2]]),
+            Triangle::from([v[3], v[7], v[6]]),
         ];
 
-        Mesh {
-            triangles,
-            position: Vec3::new(20.0, 20.0, 0.0),
-            rotation: Rotation::new(0.0, 0.0, 0.0),
+        Mesh::new().set_triangles(triangles).build()
+    }
+
+    /// Sphere made of rings of latitude and segments of longitude
+    ///
+    /// # Parameters
+    /// - `radius`: radius of the sphere
+    /// - `segments`: number of divisions around the Y axis, at least 3
+    /// - `rings`: number of divisions from pole to pole, at least 2
+    pub fn uv_sphere(radius: f64, segments: usize, rings: usize) -> Mesh {
+        let rings = rings.max(2);
+        let profile: Vec<(f64, f64)> = (0..=rings)
+            .map(|i| {
+                let angle = PI * i as f64 / rings as f64;
+                (radius * angle.sin(), -radius * angle.cos())
+            })
+            .collect();
+
+        Self::revolve(&profile, segments, 0.0)
+    }
+
+    /// Sphere made by subdividing an icosahedron, its triangles have nearly equal size
+    ///
+    /// # Parameters
+    /// - `radius`: radius of the sphere
+    /// - `subdivisions`: how many times every triangle is split into four
+    pub fn icosphere(radius: f64, subdivisions: usize) -> Mesh {
+        let t = (1.0 + 5.0_f64.sqrt()) / 2.0;
+
+        let mut vertices: Vec<Vec3> = [
+            [-1.0, t, 0.0], [1.0, t, 0.0], [-1.0, -t, 0.0], [1.0, -t, 0.0],
+            [0.0, -1.0, t], [0.0, 1.0, t], [0.0, -1.0, -t], [0.0, 1.0, -t],
+            [t, 0.0, -1.0], [t, 0.0, 1.0], [-t, 0.0, -1.0], [-t, 0.0, 1.0],
+        ]
+        .iter()
+        .map(|v| Vec3::from(*v).normalize())
+        .collect();
+
+        let mut faces: Vec<[usize; 3]> = vec![
+            [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+            [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+            [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+            [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1],
+        ];
+
+        for _ in 0..subdivisions {
+            let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+            let mut midpoint = |a: usize, b: usize, vertices: &mut Vec<Vec3>| {
+                *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                    vertices.push(vertices[a].lerp(vertices[b], 0.5).normalize());
+                    vertices.len() - 1
+                })
+            };
+
+            faces = faces
+                .iter()
+                .flat_map(|&[a, b, c]| {
+                    let ab = midpoint(a, b, &mut vertices);
+                    let bc = midpoint(b, c, &mut vertices);
+                    let ca = midpoint(c, a, &mut vertices);
+
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+
+        let triangles = faces
+            .iter()
+            .map(|&[a, b, c]| {
+                let triangle = Triangle::new([vertices[a] * radius, vertices[b] * radius, vertices[c] * radius]);
+                Self::facing_outwards(triangle, Vec3::new(0.0, 0.0, 0.0))
+            })
+            .collect();
+
+        Mesh::new().set_triangles(triangles).build()
+    }
+
+    /// Closed cylinder standing on the Y axis
+    ///
+    /// # Parameters
+    /// - `radius`: radius of the cylinder
+    /// - `height`: height of the cylinder
+    /// - `segments`: number of divisions around the Y axis, at least 3
+    pub fn cylinder(radius: f64, height: f64, segments: usize) -> Mesh {
+        let h = height / 2.0;
+
+        Self::revolve(&[(0.0, -h), (radius, -h), (radius, h), (0.0, h)], segments, 0.0)
+    }
+
+    /// Closed cone standing on the Y axis with its tip at the top
+    ///
+    /// # Parameters
+    /// - `radius`: radius of the base
+    /// - `height`: distance of the base and the tip
+    /// - `segments`: number of divisions around the Y axis, at least 3
+    pub fn cone(radius: f64, height: f64, segments: usize) -> Mesh {
+        let h = height / 2.0;
+
+        Self::revolve(&[(0.0, -h), (radius, -h), (0.0, h)], segments, 0.0)
+    }
+
+    /// Torus lying in the XZ plane
+    ///
+    /// # Parameters
+    /// - `major_radius`: distance of the tube center from the origin
+    /// - `minor_radius`: radius of the tube
+    /// - `major_segments`: number of divisions around the Y axis, at least 3
+    /// - `minor_segments`: number of divisions around the tube, at least 3
+    pub fn torus(major_radius: f64, minor_radius: f64, major_segments: usize, minor_segments: usize) -> Mesh {
+        let minor_segments = minor_segments.max(3);
+        let profile: Vec<(f64, f64)> = (0..=minor_segments)
+            .map(|i| {
+                let angle = TAU * i as f64 / minor_segments as f64;
+                (major_radius + minor_radius * angle.cos(), minor_radius * angle.sin())
+            })
+            .collect();
+
+        Self::revolve(&profile, major_segments, 0.0)
+    }
+
+    /// Flat square facing the positive Y axis
+    ///
+    /// # Parameters
+    /// - `width`: size along the X axis
+    /// - `depth`: size along the Z axis
+    pub fn plane(width: f64, depth: f64) -> Mesh {
+        Self::grid(width, depth, 1, 1)
+    }
+
+    /// Flat grid of squares facing the positive Y axis
+    ///
+    /// # Parameters
+    /// - `width`: size along the X axis
+    /// - `depth`: size along the Z axis
+    /// - `columns`: number of squares along the X axis
+    /// - `rows`: number of squares along the Z axis
+    pub fn grid(width: f64, depth: f64, columns: usize, rows: usize) -> Mesh {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let point = |i: usize, j: usize| {
+            Vec3::new(
+                width * (i as f64 / columns as f64 - 0.5),
+                0.0,
+                depth * (j as f64 / rows as f64 - 0.5),
+            )
+        };
+
+        let triangles = (0..columns)
+            .flat_map(|i| (0..rows).map(move |j| (i, j)))
+            .flat_map(|(i, j)| {
+                [
+                    Triangle::new([point(i, j), point(i, j + 1), point(i + 1, j + 1)]),
+                    Triangle::new([point(i, j), point(i + 1, j + 1), point(i + 1, j)]),
+                ]
+            })
+            .collect();
+
+        Mesh::new().set_triangles(triangles).build()
+    }
+
+    /// Cylinder with hemispherical ends standing on the Y axis
+    ///
+    /// # Parameters
+    /// - `radius`: radius of the cylinder and its ends
+    /// - `height`: total height including the ends
+    /// - `segments`: number of divisions around the Y axis, at least 3
+    /// - `rings`: number of divisions of each hemispherical end, at least 1
+    pub fn capsule(radius: f64, height: f64, segments: usize, rings: usize) -> Mesh {
+        let rings = rings.max(1);
+        let h = (height / 2.0 - radius).max(0.0);
+
+        let bottom = (0..=rings).map(|i| {
+            let angle = PI / 2.0 * i as f64 / rings as f64;
+            (radius * angle.sin(), -h - radius * angle.cos())
+        });
+        let top = (0..=rings).map(|i| {
+            let angle = PI / 2.0 * i as f64 / rings as f64;
+            (radius * angle.cos(), h + radius * angle.sin())
+        });
+
+        Self::revolve(&bottom.chain(top).collect::<Vec<_>>(), segments, 0.0)
+    }
+
+    /// Pyramid with a square base, its tip at the top
+    ///
+    /// # Parameters
+    /// - `base`: length of the base edges
+    /// - `height`: distance of the base and the tip
+    pub fn pyramid(base: f64, height: f64) -> Mesh {
+        let h = height / 2.0;
+        let corner = base / 2.0_f64.sqrt();
+
+        Self::revolve(&[(0.0, -h), (corner, -h), (0.0, h)], 4, FRAC_PI_4)
+    }
+
+    /// Arrow pointing along the positive Y axis, made of a cylindrical shaft and a conical head
+    ///
+    /// # Parameters
+    /// - `length`: total length of the arrow
+    /// - `shaft_radius`: radius of the shaft
+    /// - `head_radius`: radius of the base of the head
+    /// - `head_length`: length of the head
+    /// - `segments`: number of divisions around the Y axis, at least 3
+    pub fn arrow(length: f64, shaft_radius: f64, head_radius: f64, head_length: f64, segments: usize) -> Mesh {
+        let h = length / 2.0;
+        let neck = h - head_length.min(length);
+
+        Self::revolve(
+            &[(0.0, -h), (shaft_radius, -h), (shaft_radius, neck), (head_radius, neck), (0.0, h)],
+            segments,
+            0.0,
+        )
+    }
+
+    /// Revolves a profile around the Y axis.
+    /// Profile points are pairs of distance from the axis and height, listed so that the outside
+    /// of the surface is on the right side when walking along the profile with the axis on the left.
+    /// Points lying on the axis close the surface with a fan of triangles.
+    fn revolve(profile: &[(f64, f64)], segments: usize, offset: f64) -> Mesh {
+        let segments = segments.max(3);
+        let point = |(radius, y): (f64, f64), j: usize| {
+            let angle = offset + TAU * j as f64 / segments as f64;
+            Vec3::new(radius * angle.cos(), y, radius * angle.sin())
+        };
+
+        let mut triangles = vec![];
+
+        for pair in profile.windows(2) {
+            let (lower, upper) = (pair[0], pair[1]);
+
+            for j in 0..segments {
+                let p00 = point(lower, j);
+                let p01 = point(lower, j + 1);
+                let p10 = point(upper, j);
+                let p11 = point(upper, j + 1);
+
+                if upper.0.abs() > f64::EPSILON {
+                    triangles.push(Triangle::new([p00, p10, p11]));
+                }
+                if lower.0.abs() > f64::EPSILON {
+                    triangles.push(Triangle::new([p00, p11, p01]));
+                }
+            }
+        }
+
+        Mesh::new().set_triangles(triangles).build()
+    }
+
+    /// Flips a triangle of a convex shape if it faces towards the center
+    fn facing_outwards(triangle: Triangle, center: Vec3) -> Triangle {
+        let [a, b, c] = triangle.vertices;
+
+        if triangle.normal().dot(a - center) < 0.0 {
+            Triangle::new([a, c, b])
+        } else {
+            triangle
         }
     }
 }
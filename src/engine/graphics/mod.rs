@@ -8,6 +8,10 @@ mod rotation;
 mod vec2;
 mod gdi;
 mod buff_char;
+mod fragment;
+mod color;
+mod indexed_mesh;
+mod material;
 
 pub use vec3::*;
 pub use vec2::*;
@@ -18,3 +22,7 @@ pub use rotation::*;
 pub use gdi::*;
 pub use meshes::*;
 pub use buff_char::*;
+pub use fragment::*;
+pub use color::*;
+pub use indexed_mesh::*;
+pub use material::*;
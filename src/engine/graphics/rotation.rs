@@ -11,3 +11,36 @@ impl Rotation {
         Self { x, y, z }
     }
 }
+
+/// enum representing in which order rotations around single axes are applied.
+/// `XYZ` rotates around the X axis first, then Y and then Z, which is the order used by Meshes and Cameras.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EulerOrder {
+    #[default]
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Indices of the axes in the order they are applied, 0 being X
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    /// Whether the axes follow each other cyclically, as in X -> Y -> Z
+    pub fn is_cyclic(&self) -> bool {
+        matches!(self, EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY)
+    }
+}
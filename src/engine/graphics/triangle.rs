@@ -1,4 +1,4 @@
-use crate::{GDI, Vec2, graphics::Vec3};
+use crate::{Color, Fragment, Matrix, Vec2, graphics::Vec3};
 
 /// Triangle struct for representing a triangle in computer graphics. Used for creating Meshes
 ///
@@ -28,6 +28,12 @@ use crate::{GDI, Vec2, graphics::Vec3};
 pub struct Triangle {
     /// Array of 3 3D vectors representing corners of triangle
     pub vertices: [Vec3; 3],
+    /// Optional normals of each corner, e.g. loaded from a model file
+    pub normals: Option<[Vec3; 3]>,
+    /// Optional texture coordinates of each corner
+    pub uvs: Option<[Vec2; 3]>,
+    /// Optional colors of each corner
+    pub colors: Option<[Color; 3]>,
 }
 
 impl Triangle {
@@ -36,7 +42,37 @@ impl Triangle {
     /// # Parameters
     /// - `vertices`: Array of 3 3D vectors
     pub fn new(vertices: [Vec3; 3]) -> Self {
-        Self { vertices }
+        Self { vertices, normals: None, uvs: None, colors: None }
+    }
+
+    /// Sets normals of each corner
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_normals(mut self, normals: [Vec3; 3]) -> Self {
+        self.normals = Some(normals);
+
+        self
+    }
+
+    /// Sets colors of each corner
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_colors(mut self, colors: [Color; 3]) -> Self {
+        self.colors = Some(colors);
+
+        self
+    }
+
+    /// Sets texture coordinates of each corner
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_uvs(mut self, uvs: [Vec2; 3]) -> Self {
+        self.uvs = Some(uvs);
+
+        self
     }
 
     pub fn rasterize(&self, focal_length: f64) -> Vec<Vec2> {
@@ -80,11 +116,104 @@ impl Triangle {
         (c.x - a.x) * (b.y - a.y) - (c.y - a.y) * (b.x - a.x)
     }
 
+    /// Transforms every vertex of the triangle by a homogeneous matrix
+    ///
+    /// # Parameters
+    /// - `matrix`: 4x4 matrix to be applied on each vertex
+    ///
+    /// # Returns
+    /// new transformed Triangle, corner normals are only rotated
+    pub fn transform(&self, matrix: &Matrix<f64, 4, 4>) -> Self {
+        Self {
+            vertices: [
+                *matrix * self.vertices[0],
+                *matrix * self.vertices[1],
+                *matrix * self.vertices[2],
+            ],
+            normals: self.normals.map(|n| n.map(|n| Self::transform_normal(matrix, n))),
+            uvs: self.uvs,
+            colors: self.colors,
+        }
+    }
+
+    /// Applies only the linear part of a homogeneous matrix on a normal
+    ///
+    /// # Returns
+    /// unit normal, or the input itself when it has no length
+    pub fn transform_normal(matrix: &Matrix<f64, 4, 4>, normal: Vec3) -> Vec3 {
+        let linear = Matrix([
+            [matrix[(0, 0)], matrix[(0, 1)], matrix[(0, 2)]],
+            [matrix[(1, 0)], matrix[(1, 1)], matrix[(1, 2)]],
+            [matrix[(2, 0)], matrix[(2, 1)], matrix[(2, 2)]],
+        ]);
+
+        (linear * normal).try_normalize().unwrap_or(normal)
+    }
+
+    /// Unit normal of the triangle following the right hand rule.
+    /// Degenerate triangles with no area have a zero normal.
     pub fn normal(&self) -> Vec3 {
         let line1 = self.vertices[1] - self.vertices[0];
         let line2 = self.vertices[2] - self.vertices[0];
 
-        line1.cross(line2).normalize()
+        line1.cross(line2).try_normalize().unwrap_or(Vec3::new(0.0, 0.0, 0.0))
+    }
+
+    /// Point inside the triangle described by barycentric weights
+    ///
+    /// # Parameters
+    /// - `weights`: weight of each corner, summing up to 1
+    pub fn interpolate_vertex(&self, weights: [f64; 3]) -> Vec3 {
+        self.vertices[0] * weights[0] + self.vertices[1] * weights[1] + self.vertices[2] * weights[2]
+    }
+
+    /// Unit normal blended from the corner normals
+    ///
+    /// # Parameters
+    /// - `weights`: weight of each corner, summing up to 1
+    ///
+    /// # Returns
+    /// None when the triangle has no corner normals
+    pub fn interpolate_normal(&self, weights: [f64; 3]) -> Option<Vec3> {
+        self.normals.map(|n| {
+            let normal = n[0] * weights[0] + n[1] * weights[1] + n[2] * weights[2];
+            normal.try_normalize().unwrap_or(normal)
+        })
+    }
+
+    /// Texture coordinates blended from the corner texture coordinates
+    ///
+    /// # Parameters
+    /// - `weights`: weight of each corner, summing up to 1
+    pub fn interpolate_uv(&self, weights: [f64; 3]) -> Option<Vec2> {
+        self.uvs.map(|uv| uv[0] * weights[0] + uv[1] * weights[1] + uv[2] * weights[2])
+    }
+
+    /// Color blended from the corner colors
+    ///
+    /// # Parameters
+    /// - `weights`: weight of each corner, summing up to 1
+    pub fn interpolate_color(&self, weights: [f64; 3]) -> Option<Color> {
+        self.colors.map(|c| {
+            let channel = |f: fn(&Color) -> u8| {
+                (0..3).map(|i| f(&c[i]) as f64 * weights[i]).sum::<f64>().round().clamp(0.0, 255.0) as u8
+            };
+
+            Color::new(channel(|c| c.r), channel(|c| c.g), channel(|c| c.b))
+        })
+    }
+
+    /// Builds a triangle lying inside this one, blending all corner attributes
+    ///
+    /// # Parameters
+    /// - `weights`: barycentric weights of each new corner relative to this triangle
+    pub fn sub_triangle(&self, weights: [[f64; 3]; 3]) -> Self {
+        Self {
+            vertices: weights.map(|w| self.interpolate_vertex(w)),
+            normals: self.normals.map(|_| weights.map(|w| self.interpolate_normal(w).unwrap())),
+            uvs: self.uvs.map(|_| weights.map(|w| self.interpolate_uv(w).unwrap())),
+            colors: self.colors.map(|_| weights.map(|w| self.interpolate_color(w).unwrap())),
+        }
     }
 
     /// Rasterizes a 2D triangle and returns all pixels that should be filled
@@ -135,6 +264,54 @@ impl Triangle {
         pixels
     }
 
+    /// Rasterizes a 2D triangle together with the depth of its corners
+    ///
+    /// # Parameters
+    /// - `vertices`: Array of 3 Vec2 vertices representing the triangle corners on the screen
+    /// - `depths`: distance of each corner from the camera
+    /// - `perspective`: whether the corners were projected perspectively
+    ///
+    /// # Returns
+    /// Vector of fragments with interpolated depth for every pixel inside the triangle
+    pub fn rasterize_2d_triangle_with_depth(vertices: [Vec2; 3], depths: [f64; 3], perspective: bool) -> Vec<Fragment> {
+        Self::rasterize_2d_triangle(vertices)
+            .into_iter()
+            .filter_map(|p| {
+                let b = Self::barycentric_2d(vertices, p)?;
+
+                if !perspective {
+                    let depth = b[0] * depths[0] + b[1] * depths[1] + b[2] * depths[2];
+                    return Some(Fragment::new(p, depth, b));
+                }
+
+                // Depth is not linear in screen space, its reciprocal is
+                let w = [b[0] / depths[0], b[1] / depths[1], b[2] / depths[2]];
+                let inv_depth = w[0] + w[1] + w[2];
+
+                Some(Fragment::new(p, 1.0 / inv_depth, [w[0] / inv_depth, w[1] / inv_depth, w[2] / inv_depth]))
+            })
+            .collect()
+    }
+
+    /// Calculates barycentric weights of a point relative to a 2D triangle.
+    /// Weights are clamped to the triangle so pixels on its rounded edges stay valid.
+    ///
+    /// # Returns
+    /// weights of each corner or None for a degenerate triangle
+    pub fn barycentric_2d(vertices: [Vec2; 3], p: Vec2) -> Option<[f64; 3]> {
+        let area = Self::edge(vertices[0], vertices[1], vertices[2]);
+        if area.abs() < f64::EPSILON {
+            return None;
+        }
+
+        let w0 = (Self::edge(vertices[1], vertices[2], p) / area).max(0.0);
+        let w1 = (Self::edge(vertices[2], vertices[0], p) / area).max(0.0);
+        let w2 = (Self::edge(vertices[0], vertices[1], p) / area).max(0.0);
+        let sum = w0 + w1 + w2;
+
+        Some([w0 / sum, w1 / sum, w2 / sum])
+    }
+
     /// Helper function to fill a horizontal scanline between two x coordinates
     fn fill_scanline(pixels: &mut Vec<Vec2>, x_start: f64, x_end: f64, y: f64) {
         let x_start_int = x_start.ceil() as i32;
@@ -1,11 +1,11 @@
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[allow(unused_imports)]
 use crate::prelude::*;
 
 /// 2D mathematical vector representation
 #[allow(dead_code)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vec2 {
     /// X axis location of the vector
     pub x: f64,
@@ -18,6 +18,112 @@ impl Vec2 {
     pub fn new(x: f64, y: f64) -> Self {
         Self { x, y }
     }
+
+    pub fn dot(self, rhs: Vec2) -> f64 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Scales the vector to unit length.
+    /// Zero vector has no direction and produces NaN components, see `try_normalize`.
+    pub fn normalize(self) -> Vec2 {
+        self / self.length()
+    }
+
+    /// Scales the vector to unit length
+    ///
+    /// # Returns
+    /// unit vector or None if the vector is too short to have a direction
+    pub fn try_normalize(self) -> Option<Vec2> {
+        let len = self.length();
+
+        if len > f64::EPSILON && len.is_finite() {
+            Some(self / len)
+        } else {
+            None
+        }
+    }
+
+    pub fn distance(self, rhs: Vec2) -> f64 {
+        (rhs - self).length()
+    }
+
+    /// Multiplies vectors component by component
+    pub fn component_mul(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+
+    /// Linear interpolation between two vectors
+    ///
+    /// # Parameters
+    /// - `rhs`: vector reached at `t = 1`
+    /// - `t`: interpolation factor
+    pub fn lerp(self, rhs: Vec2, t: f64) -> Vec2 {
+        self + (rhs - self) * t
+    }
+
+    /// Reflects the vector off a line
+    ///
+    /// # Parameters
+    /// - `normal`: unit normal of the line
+    pub fn reflect(self, normal: Vec2) -> Vec2 {
+        self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Projects the vector onto another one
+    ///
+    /// # Returns
+    /// component of the vector parallel to `onto`, zero vector if `onto` is zero
+    pub fn project(self, onto: Vec2) -> Vec2 {
+        let len_sq = onto.dot(onto);
+
+        if len_sq == 0.0 {
+            return Vec2::new(0.0, 0.0);
+        }
+
+        onto * (self.dot(onto) / len_sq)
+    }
+
+    pub fn min(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x.min(rhs.x), self.y.min(rhs.y))
+    }
+
+    pub fn max(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x.max(rhs.x), self.y.max(rhs.y))
+    }
+
+    pub fn abs(self) -> Vec2 {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+}
+
+impl From<[f64; 2]> for Vec2 {
+    fn from(value: [f64; 2]) -> Self {
+        Self { x: value[0], y: value[1] }
+    }
+}
+
+/// Dropping the Z component of a 3D vector
+impl From<Vec3> for Vec2 {
+    fn from(value: Vec3) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+/// Using a vector as a column of a matrix
+impl From<Vec2> for Matrix<f64, 2, 1> {
+    fn from(value: Vec2) -> Self {
+        Matrix([[value.x], [value.y]])
+    }
+}
+
+impl From<Matrix<f64, 2, 1>> for Vec2 {
+    fn from(value: Matrix<f64, 2, 1>) -> Self {
+        Vec2::new(value[(0, 0)], value[(1, 0)])
+    }
 }
 
 impl Add<Self> for Vec2 {
@@ -39,3 +145,55 @@ impl Sub<Self> for Vec2 {
         }
     }
 }
+
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Div<f64> for Vec2 {
+    type Output = Vec2;
+    fn div(self, rhs: f64) -> Self::Output {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign<Vec2> for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vec2> for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<f64> for Vec2 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Vec2 {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
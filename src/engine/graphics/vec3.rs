@@ -1,5 +1,5 @@
-use crate::{Vec2};
-use std::{ops::{Add, Sub}};
+use crate::{Matrix, Vec2};
+use std::{ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 
 /// Struct representing a mathematical 3D vector
 ///
@@ -65,6 +65,8 @@ impl Vec3 {
         self.dot(self).sqrt()
     }
 
+    /// Scales the vector to unit length.
+    /// Zero vector has no direction and produces NaN components, see `try_normalize`.
     pub fn normalize(self) -> Vec3 {
         let len = self.length();
 
@@ -74,6 +76,72 @@ impl Vec3 {
             self.z / len
         ].into()
     }
+
+    /// Scales the vector to unit length
+    ///
+    /// # Returns
+    /// unit vector or None if the vector is too short to have a direction
+    pub fn try_normalize(self) -> Option<Vec3> {
+        let len = self.length();
+
+        if len > f64::EPSILON && len.is_finite() {
+            Some(self / len)
+        } else {
+            None
+        }
+    }
+
+    pub fn distance(self, rhs: Vec3) -> f64 {
+        (rhs - self).length()
+    }
+
+    /// Multiplies vectors component by component
+    pub fn component_mul(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+
+    /// Linear interpolation between two vectors
+    ///
+    /// # Parameters
+    /// - `rhs`: vector reached at `t = 1`
+    /// - `t`: interpolation factor
+    pub fn lerp(self, rhs: Vec3, t: f64) -> Vec3 {
+        self + (rhs - self) * t
+    }
+
+    /// Reflects the vector off a surface
+    ///
+    /// # Parameters
+    /// - `normal`: unit normal of the surface
+    pub fn reflect(self, normal: Vec3) -> Vec3 {
+        self - normal * (2.0 * self.dot(normal))
+    }
+
+    /// Projects the vector onto another one
+    ///
+    /// # Returns
+    /// component of the vector parallel to `onto`, zero vector if `onto` is zero
+    pub fn project(self, onto: Vec3) -> Vec3 {
+        let len_sq = onto.dot(onto);
+
+        if len_sq == 0.0 {
+            return Vec3::new(0.0, 0.0, 0.0);
+        }
+
+        onto * (self.dot(onto) / len_sq)
+    }
+
+    pub fn min(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    pub fn max(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    pub fn abs(self) -> Vec3 {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
 }
 
 /// Implementation of From trait for flexibility when constructing a Vec3
@@ -147,3 +215,100 @@ impl Sub<Vec3> for Vec3 {
         ].into()
     }
 }
+
+/// Implementation of * operator scaling a vector
+///
+/// # Examples
+///
+/// ```
+/// use rustender::prelude::*;
+///
+/// let vec: Vec3 = [1.0, 2.0, 3.0].into();
+///
+/// // returns new Vec3 (2.0, 4.0, 6.0)
+/// let result = vec * 2.0;
+/// let result = 2.0 * vec;
+/// ```
+impl Mul<f64> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Vec3> for f64 {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// Implementation of / operator dividing every component by a scalar
+impl Div<f64> for Vec3 {
+    type Output = Vec3;
+    fn div(self, rhs: f64) -> Self::Output {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+    fn neg(self) -> Self::Output {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl AddAssign<Vec3> for Vec3 {
+    fn add_assign(&mut self, rhs: Vec3) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vec3> for Vec3 {
+    fn sub_assign(&mut self, rhs: Vec3) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<f64> for Vec3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Vec3 {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Extending a 2D vector with zero Z component
+impl From<Vec2> for Vec3 {
+    fn from(value: Vec2) -> Self {
+        Vec3::new(value.x, value.y, 0.0)
+    }
+}
+
+/// Using a vector as a column of a matrix
+///
+/// # Examples
+///
+/// ```
+/// use rustender::prelude::*;
+///
+/// let column: Matrix<f64, 3, 1> = Vec3::new(1.0, 2.0, 3.0).into();
+///
+/// // returns Matrix([[1.0, 2.0, 3.0]])
+/// let row = column.transpose();
+/// ```
+impl From<Vec3> for Matrix<f64, 3, 1> {
+    fn from(value: Vec3) -> Self {
+        Matrix([[value.x], [value.y], [value.z]])
+    }
+}
+
+impl From<Matrix<f64, 3, 1>> for Vec3 {
+    fn from(value: Matrix<f64, 3, 1>) -> Self {
+        Vec3::new(value[(0, 0)], value[(1, 0)], value[(2, 0)])
+    }
+}
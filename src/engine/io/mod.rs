@@ -0,0 +1,7 @@
+mod obj;
+mod stl;
+mod ply;
+
+pub use obj::*;
+pub use stl::*;
+pub use ply::*;
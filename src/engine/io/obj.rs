@@ -0,0 +1,226 @@
+use std::{fmt::Display, fs, path::Path};
+
+use crate::prelude::*;
+
+/// Error produced while loading a Wavefront OBJ file
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum ObjError {
+    /// File could not be read
+    Io(std::io::Error),
+    /// Line of the file could not be understood
+    Parse {
+        /// Line number starting from 1
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for ObjError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "failed to read OBJ file: {e}"),
+            ObjError::Parse { line, message } => write!(f, "OBJ line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+impl From<std::io::Error> for ObjError {
+    fn from(value: std::io::Error) -> Self {
+        ObjError::Io(value)
+    }
+}
+
+/// Part of an OBJ file started by an `o` or `g` statement
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjGroup {
+    /// Name of the object set by the last `o` statement
+    pub object: Option<String>,
+    /// Name of the group set by the last `g` statement
+    pub group: Option<String>,
+    /// Faces of the group
+    pub mesh: Mesh,
+}
+
+/// Loader of Wavefront OBJ models
+///
+/// Faces with more than three vertices are split into a triangle fan,
+/// vertex normals and texture coordinates are kept on the triangles when present.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let source = "
+/// v 0 0 0
+/// v 1 0 0
+/// v 1 1 0
+/// v 0 1 0
+/// f 1 2 3 4
+/// ";
+///
+/// let mesh = OBJ::parse_mesh(source).unwrap();
+///
+/// assert_eq!(mesh.triangles.len(), 2);
+/// ```
+#[allow(dead_code)]
+pub struct OBJ;
+
+impl OBJ {
+    /// Reads an OBJ file and merges all of its groups into a single Mesh
+    pub fn load_mesh(path: impl AsRef<Path>) -> Result<Mesh, ObjError> {
+        Self::parse_mesh(&fs::read_to_string(path)?)
+    }
+
+    /// Reads an OBJ file keeping its objects and groups apart
+    pub fn load(path: impl AsRef<Path>) -> Result<Vec<ObjGroup>, ObjError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses OBJ source and merges all of its groups into a single Mesh
+    pub fn parse_mesh(source: &str) -> Result<Mesh, ObjError> {
+        let triangles = Self::parse(source)?.into_iter().flat_map(|g| g.mesh.triangles).collect();
+
+        Ok(Mesh::new().set_triangles(triangles).build())
+    }
+
+    /// Parses OBJ source keeping its objects and groups apart
+    ///
+    /// # Returns
+    /// every non empty group in order of appearance
+    pub fn parse(source: &str) -> Result<Vec<ObjGroup>, ObjError> {
+        let mut positions: Vec<Vec3> = vec![];
+        let mut uvs: Vec<Vec2> = vec![];
+        let mut normals: Vec<Vec3> = vec![];
+
+        let mut groups = vec![];
+        let mut current = ObjGroup { object: None, group: None, mesh: Mesh::new().build() };
+
+        for (i, line) in source.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.split('#').next().unwrap_or("").trim();
+            let mut tokens = line.split_whitespace();
+
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let args: Vec<&str> = tokens.collect();
+
+            match keyword {
+                "v" => positions.push(Self::parse_vec3(&args, line_number)?),
+                "vn" => normals.push(Self::parse_vec3(&args, line_number)?),
+                "vt" => {
+                    let u = Self::parse_float(args.first(), line_number)?;
+                    let v = match args.get(1) {
+                        Some(_) => Self::parse_float(args.get(1), line_number)?,
+                        None => 0.0,
+                    };
+                    uvs.push(Vec2::new(u, v));
+                }
+                "f" => {
+                    if args.len() < 3 {
+                        return Err(Self::error(line_number, "face needs at least 3 vertices"));
+                    }
+
+                    let corners = args
+                        .iter()
+                        .map(|a| Self::parse_corner(a, &positions, &uvs, &normals, line_number))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    for k in 1..corners.len() - 1 {
+                        let [a, b, c] = [&corners[0], &corners[k], &corners[k + 1]];
+                        let mut triangle = Triangle::new([a.0, b.0, c.0]);
+
+                        if let (Some(ua), Some(ub), Some(uc)) = (a.1, b.1, c.1) {
+                            triangle = triangle.with_uvs([ua, ub, uc]);
+                        }
+                        if let (Some(na), Some(nb), Some(nc)) = (a.2, b.2, c.2) {
+                            triangle = triangle.with_normals([na, nb, nc]);
+                        }
+
+                        current.mesh.triangles.push(triangle);
+                    }
+                }
+                "o" | "g" => {
+                    let name = if args.is_empty() { None } else { Some(args.join(" ")) };
+                    let object = if keyword == "o" { name.clone() } else { current.object.clone() };
+                    let group = if keyword == "g" { name } else { None };
+
+                    let finished = std::mem::replace(&mut current, ObjGroup { object, group, mesh: Mesh::new().build() });
+                    if !finished.mesh.triangles.is_empty() {
+                        groups.push(finished);
+                    }
+                }
+                // materials, smoothing groups, lines and points are not used by the renderer
+                _ => {}
+            }
+        }
+
+        if !current.mesh.triangles.is_empty() {
+            groups.push(current);
+        }
+
+        Ok(groups)
+    }
+
+    fn error(line: usize, message: impl Into<String>) -> ObjError {
+        ObjError::Parse { line, message: message.into() }
+    }
+
+    fn parse_float(token: Option<&&str>, line: usize) -> Result<f64, ObjError> {
+        let token = token.ok_or_else(|| Self::error(line, "missing number"))?;
+
+        token.parse().map_err(|_| Self::error(line, format!("invalid number `{token}`")))
+    }
+
+    fn parse_vec3(args: &[&str], line: usize) -> Result<Vec3, ObjError> {
+        Ok(Vec3::new(
+            Self::parse_float(args.first(), line)?,
+            Self::parse_float(args.get(1), line)?,
+            Self::parse_float(args.get(2), line)?,
+        ))
+    }
+
+    /// Resolves a 1 based or negative relative OBJ index
+    fn resolve<T: Copy>(token: &str, items: &[T], kind: &str, line: usize) -> Result<T, ObjError> {
+        let index: isize = token
+            .parse()
+            .map_err(|_| Self::error(line, format!("invalid {kind} index `{token}`")))?;
+
+        let resolved = match index {
+            0 => None,
+            i if i > 0 => Some(i as usize - 1),
+            i => items.len().checked_sub(i.unsigned_abs()),
+        };
+
+        resolved
+            .and_then(|i| items.get(i).copied())
+            .ok_or_else(|| Self::error(line, format!("{kind} index {index} out of range")))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn parse_corner(
+        token: &str,
+        positions: &[Vec3],
+        uvs: &[Vec2],
+        normals: &[Vec3],
+        line: usize,
+    ) -> Result<(Vec3, Option<Vec2>, Option<Vec3>), ObjError> {
+        let mut parts = token.split('/');
+
+        let position = Self::resolve(parts.next().unwrap_or(""), positions, "vertex", line)?;
+        let uv = match parts.next() {
+            Some(t) if !t.is_empty() => Some(Self::resolve(t, uvs, "texture", line)?),
+            _ => None,
+        };
+        let normal = match parts.next() {
+            Some(t) if !t.is_empty() => Some(Self::resolve(t, normals, "normal", line)?),
+            _ => None,
+        };
+
+        Ok((position, uv, normal))
+    }
+}
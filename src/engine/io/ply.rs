@@ -0,0 +1,391 @@
+use std::{fmt::Display, fs, path::Path};
+
+use crate::prelude::*;
+
+/// Error produced while reading a Stanford PLY file
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum PlyError {
+    /// File could not be read
+    Io(std::io::Error),
+    /// Header line could not be understood
+    Header {
+        /// Line number starting from 1
+        line: usize,
+        message: String,
+    },
+    /// Element data after the header is invalid
+    Data {
+        /// Name of the element being read
+        element: String,
+        /// Index of the element being read
+        index: usize,
+        message: String,
+    },
+}
+
+impl Display for PlyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlyError::Io(e) => write!(f, "failed to read PLY file: {e}"),
+            PlyError::Header { line, message } => write!(f, "PLY header line {line}: {message}"),
+            PlyError::Data { element, index, message } => write!(f, "PLY {element} {index}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for PlyError {}
+
+impl From<std::io::Error> for PlyError {
+    fn from(value: std::io::Error) -> Self {
+        PlyError::Io(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScalarType {
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "char" | "int8" => ScalarType::Int8,
+            "uchar" | "uint8" => ScalarType::UInt8,
+            "short" | "int16" => ScalarType::Int16,
+            "ushort" | "uint16" => ScalarType::UInt16,
+            "int" | "int32" => ScalarType::Int32,
+            "uint" | "uint32" => ScalarType::UInt32,
+            "float" | "float32" => ScalarType::Float32,
+            "double" | "float64" => ScalarType::Float64,
+            _ => return None,
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            ScalarType::Int8 | ScalarType::UInt8 => 1,
+            ScalarType::Int16 | ScalarType::UInt16 => 2,
+            ScalarType::Int32 | ScalarType::UInt32 | ScalarType::Float32 => 4,
+            ScalarType::Float64 => 8,
+        }
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(self, ScalarType::Float32 | ScalarType::Float64)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Property {
+    Scalar { name: String, ty: ScalarType },
+    List { name: String, count: ScalarType, item: ScalarType },
+}
+
+#[derive(Debug, Clone)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+/// Cursor over the element data of a PLY file
+enum Data<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary { bytes: &'a [u8], position: usize, big_endian: bool },
+}
+
+impl Data<'_> {
+    fn read(&mut self, ty: ScalarType) -> Result<f64, String> {
+        match self {
+            Data::Ascii(tokens) => {
+                let token = tokens.next().ok_or("unexpected end of file")?;
+                token.parse::<f64>().map_err(|_| format!("invalid number `{token}`"))
+            }
+            Data::Binary { bytes, position, big_endian } => {
+                let size = ty.size();
+                let raw = bytes.get(*position..*position + size).ok_or("unexpected end of file")?;
+                *position += size;
+
+                let mut buf = [0u8; 8];
+                buf[..size].copy_from_slice(raw);
+                if *big_endian {
+                    buf[..size].reverse();
+                }
+
+                Ok(match ty {
+                    ScalarType::Int8 => buf[0] as i8 as f64,
+                    ScalarType::UInt8 => buf[0] as f64,
+                    ScalarType::Int16 => i16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::UInt16 => u16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    ScalarType::Int32 => i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::UInt32 => u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::Float32 => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+                    ScalarType::Float64 => f64::from_le_bytes(buf),
+                })
+            }
+        }
+    }
+}
+
+/// Attributes of a single PLY vertex
+#[derive(Debug, Clone, Copy)]
+struct PlyVertex {
+    position: Vec3,
+    normal: Option<Vec3>,
+    color: Option<Color>,
+}
+
+/// Reader of Stanford PLY models in ASCII and binary little and big endian form
+///
+/// Faces are split into triangle fans. Per-vertex normals (`nx`, `ny`, `nz`) and colors
+/// (`red`, `green`, `blue`) are kept on the triangles when present, other elements and properties are skipped.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let source = b"ply
+/// format ascii 1.0
+/// element vertex 3
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// element face 1
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 255 0 0
+/// 1 0 0 0 255 0
+/// 0 1 0 0 0 255
+/// 3 0 1 2
+/// ";
+///
+/// let mesh = PLY::read(source).unwrap();
+///
+/// assert_eq!(mesh.triangles[0].colors.unwrap()[0], Color::new(255, 0, 0));
+/// ```
+#[allow(dead_code)]
+pub struct PLY;
+
+impl PLY {
+    /// Reads a PLY file
+    pub fn load(path: impl AsRef<Path>) -> Result<Mesh, PlyError> {
+        Self::read(&fs::read(path)?)
+    }
+
+    /// Reads PLY content
+    pub fn read(bytes: &[u8]) -> Result<Mesh, PlyError> {
+        let (format, elements, body) = Self::read_header(bytes)?;
+
+        let mut data = match format {
+            PlyFormat::Ascii => {
+                let source = std::str::from_utf8(body).map_err(|_| PlyError::Data {
+                    element: "body".into(),
+                    index: 0,
+                    message: "ASCII data is not valid UTF-8".into(),
+                })?;
+                Data::Ascii(source.split_ascii_whitespace())
+            }
+            PlyFormat::BinaryLittleEndian => Data::Binary { bytes: body, position: 0, big_endian: false },
+            PlyFormat::BinaryBigEndian => Data::Binary { bytes: body, position: 0, big_endian: true },
+        };
+
+        let mut vertices: Vec<PlyVertex> = vec![];
+        let mut triangles = vec![];
+
+        for element in &elements {
+            for index in 0..element.count {
+                let error = |message: String| PlyError::Data { element: element.name.clone(), index, message };
+
+                let mut scalars: Vec<(&str, f64, ScalarType)> = vec![];
+                let mut indices: Option<Vec<usize>> = None;
+
+                for property in &element.properties {
+                    match property {
+                        Property::Scalar { name, ty } => scalars.push((name, data.read(*ty).map_err(error)?, *ty)),
+                        Property::List { name, count, item } => {
+                            let len = data.read(*count).map_err(error)?;
+                            if !Self::is_index(len) {
+                                return Err(error("invalid list length".into()));
+                            }
+
+                            let items = (0..len as usize)
+                                .map(|_| data.read(*item))
+                                .collect::<Result<Vec<_>, _>>()
+                                .map_err(error)?;
+
+                            if name == "vertex_indices" || name == "vertex_index" {
+                                if !items.iter().all(|i| Self::is_index(*i)) {
+                                    return Err(error("invalid vertex index".into()));
+                                }
+
+                                indices = Some(items.into_iter().map(|i| i as usize).collect());
+                            }
+                        }
+                    }
+                }
+
+                match element.name.as_str() {
+                    "vertex" => vertices.push(Self::vertex(&scalars).map_err(error)?),
+                    "face" => {
+                        let indices = indices.ok_or_else(|| error("face has no vertex_indices".into()))?;
+                        if indices.len() < 3 {
+                            return Err(error("face needs at least 3 vertices".into()));
+                        }
+
+                        let corners = indices
+                            .iter()
+                            .map(|i| vertices.get(*i).copied())
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(|| error("vertex index out of range".into()))?;
+
+                        for k in 1..corners.len() - 1 {
+                            triangles.push(Self::triangle([corners[0], corners[k], corners[k + 1]]));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(Mesh::new().set_triangles(triangles).build())
+    }
+
+    /// Checks whether a value read from the file is usable as a count or an index
+    fn is_index(value: f64) -> bool {
+        value >= 0.0 && value.fract() == 0.0
+    }
+
+    fn vertex(scalars: &[(&str, f64, ScalarType)]) -> Result<PlyVertex, String> {
+        let get = |name: &str| scalars.iter().find(|s| s.0 == name);
+        let required = |name: &str| get(name).map(|s| s.1).ok_or(format!("vertex has no `{name}` property"));
+
+        let position = Vec3::new(required("x")?, required("y")?, required("z")?);
+
+        let normal = match (get("nx"), get("ny"), get("nz")) {
+            (Some(x), Some(y), Some(z)) => Some(Vec3::new(x.1, y.1, z.1)),
+            _ => None,
+        };
+
+        let channel = |s: &(&str, f64, ScalarType)| {
+            if s.2.is_float() {
+                (s.1 * 255.0).round().clamp(0.0, 255.0) as u8
+            } else {
+                s.1.clamp(0.0, 255.0) as u8
+            }
+        };
+        let color = match (get("red"), get("green"), get("blue")) {
+            (Some(r), Some(g), Some(b)) => Some(Color::new(channel(r), channel(g), channel(b))),
+            _ => None,
+        };
+
+        Ok(PlyVertex { position, normal, color })
+    }
+
+    fn triangle(corners: [PlyVertex; 3]) -> Triangle {
+        let mut triangle = Triangle::new(corners.map(|c| c.position));
+
+        if let [Some(a), Some(b), Some(c)] = corners.map(|c| c.normal) {
+            triangle = triangle.with_normals([a, b, c]);
+        }
+        if let [Some(a), Some(b), Some(c)] = corners.map(|c| c.color) {
+            triangle = triangle.with_colors([a, b, c]);
+        }
+
+        triangle
+    }
+
+    fn read_header(bytes: &[u8]) -> Result<(PlyFormat, Vec<Element>, &[u8]), PlyError> {
+        let error = |line: usize, message: &str| PlyError::Header { line, message: message.into() };
+
+        let mut format = None;
+        let mut elements: Vec<Element> = vec![];
+        let mut position = 0;
+        let mut line_number = 0;
+
+        loop {
+            let end = bytes[position..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .ok_or_else(|| error(line_number + 1, "missing `end_header`"))?;
+            let raw = &bytes[position..position + end];
+            position += end + 1;
+            line_number += 1;
+
+            let line = std::str::from_utf8(raw).map_err(|_| error(line_number, "header is not valid text"))?.trim();
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            if line_number == 1 {
+                if line != "ply" {
+                    return Err(error(1, "missing `ply` magic number"));
+                }
+                continue;
+            }
+
+            match tokens.first().copied() {
+                Some("format") => {
+                    format = Some(match tokens.get(1).copied() {
+                        Some("ascii") => PlyFormat::Ascii,
+                        Some("binary_little_endian") => PlyFormat::BinaryLittleEndian,
+                        Some("binary_big_endian") => PlyFormat::BinaryBigEndian,
+                        _ => return Err(error(line_number, "unknown format")),
+                    });
+                }
+                Some("element") => {
+                    let (Some(name), Some(count)) = (tokens.get(1), tokens.get(2).and_then(|c| c.parse().ok())) else {
+                        return Err(error(line_number, "element needs a name and a count"));
+                    };
+                    elements.push(Element { name: name.to_string(), count, properties: vec![] });
+                }
+                Some("property") => {
+                    let element = elements
+                        .last_mut()
+                        .ok_or_else(|| error(line_number, "property declared before any element"))?;
+                    let ty = |i: usize| {
+                        tokens
+                            .get(i)
+                            .and_then(|t| ScalarType::parse(t))
+                            .ok_or_else(|| error(line_number, "unknown property type"))
+                    };
+                    let name = |i: usize| {
+                        tokens
+                            .get(i)
+                            .map(|n| n.to_string())
+                            .ok_or_else(|| error(line_number, "property needs a name"))
+                    };
+
+                    let property = if tokens.get(1) == Some(&"list") {
+                        Property::List { count: ty(2)?, item: ty(3)?, name: name(4)? }
+                    } else {
+                        Property::Scalar { ty: ty(1)?, name: name(2)? }
+                    };
+                    element.properties.push(property);
+                }
+                Some("end_header") => break,
+                Some("comment") | Some("obj_info") | None => {}
+                Some(_) => return Err(error(line_number, "unknown header keyword")),
+            }
+        }
+
+        let format = format.ok_or_else(|| error(line_number, "missing `format` line"))?;
+
+        Ok((format, elements, &bytes[position..]))
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use crate::prelude::*;
+
+const HEADER_SIZE: usize = 80;
+const FACET_SIZE: usize = 50;
+
+/// Error produced while reading or writing an STL file
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum StlError {
+    /// File could not be read or written
+    Io(std::io::Error),
+    /// Binary file ended before all announced facets were read
+    Truncated {
+        /// Number of bytes the header announced
+        expected: usize,
+        /// Number of bytes actually present
+        found: usize,
+    },
+    /// Line of an ASCII file could not be understood
+    Parse {
+        /// Line number starting from 1
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for StlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StlError::Io(e) => write!(f, "failed to access STL file: {e}"),
+            StlError::Truncated { expected, found } => {
+                write!(f, "STL file is truncated, expected {expected} bytes but found {found}")
+            }
+            StlError::Parse { line, message } => write!(f, "STL line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for StlError {}
+
+impl From<std::io::Error> for StlError {
+    fn from(value: std::io::Error) -> Self {
+        StlError::Io(value)
+    }
+}
+
+/// Reader and writer of STL files in both ASCII and binary form
+///
+/// Every facet maps to a single Triangle. Facet normals stored in a file are ignored on import
+/// and computed by `Triangle::normal` on export.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let cube = MESHES::cube(1.0);
+///
+/// let mut bytes = vec![];
+/// STL::write_binary(&cube, &mut bytes).unwrap();
+///
+/// let loaded = STL::read(&bytes).unwrap();
+///
+/// assert_eq!(loaded.triangles.len(), cube.triangles.len());
+/// ```
+#[allow(dead_code)]
+pub struct STL;
+
+impl STL {
+    /// Reads an STL file, detecting whether it is ASCII or binary
+    pub fn load(path: impl AsRef<Path>) -> Result<Mesh, StlError> {
+        Self::read(&fs::read(path)?)
+    }
+
+    /// Writes a Mesh into an ASCII STL file
+    pub fn save_ascii(mesh: &Mesh, path: impl AsRef<Path>) -> Result<(), StlError> {
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        Self::write_ascii(mesh, &mut writer)?;
+
+        Ok(writer.flush()?)
+    }
+
+    /// Writes a Mesh into a binary STL file
+    pub fn save_binary(mesh: &Mesh, path: impl AsRef<Path>) -> Result<(), StlError> {
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        Self::write_binary(mesh, &mut writer)?;
+
+        Ok(writer.flush()?)
+    }
+
+    /// Reads STL content, detecting whether it is ASCII or binary.
+    /// Binary files may start with `solid` as well, so the size announced in the binary header decides first.
+    pub fn read(bytes: &[u8]) -> Result<Mesh, StlError> {
+        if bytes.len() >= HEADER_SIZE + 4 {
+            let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+
+            if HEADER_SIZE + 4 + count * FACET_SIZE == bytes.len() {
+                return Self::read_binary(bytes);
+            }
+        }
+
+        if bytes.trim_ascii_start().starts_with(b"solid") {
+            let source = std::str::from_utf8(bytes).map_err(|e| StlError::Parse {
+                line: bytes[..e.valid_up_to()].iter().filter(|b| **b == b'\n').count() + 1,
+                message: "invalid UTF-8".into(),
+            })?;
+
+            return Self::read_ascii(source);
+        }
+
+        Self::read_binary(bytes)
+    }
+
+    /// Reads binary STL content
+    pub fn read_binary(bytes: &[u8]) -> Result<Mesh, StlError> {
+        if bytes.len() < HEADER_SIZE + 4 {
+            return Err(StlError::Truncated { expected: HEADER_SIZE + 4, found: bytes.len() });
+        }
+
+        let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+        let expected = HEADER_SIZE + 4 + count * FACET_SIZE;
+
+        if bytes.len() < expected {
+            return Err(StlError::Truncated { expected, found: bytes.len() });
+        }
+
+        let float = |at: usize| f32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]) as f64;
+        let vec3 = |at: usize| Vec3::new(float(at), float(at + 4), float(at + 8));
+
+        let triangles = (0..count)
+            .map(|i| {
+                // facet normal takes the first 12 bytes of a facet
+                let at = HEADER_SIZE + 4 + i * FACET_SIZE + 12;
+                Triangle::new([vec3(at), vec3(at + 12), vec3(at + 24)])
+            })
+            .collect();
+
+        Ok(Mesh::new().set_triangles(triangles).build())
+    }
+
+    /// Reads ASCII STL content
+    pub fn read_ascii(source: &str) -> Result<Mesh, StlError> {
+        let error = |line: usize, message: &str| StlError::Parse { line, message: message.into() };
+
+        let mut lines = source
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l.trim()))
+            .filter(|(_, l)| !l.is_empty());
+
+        let mut last_line = match lines.next() {
+            Some((line, l)) if l.starts_with("solid") => line,
+            Some((line, _)) => return Err(error(line, "expected `solid`")),
+            None => return Err(error(1, "file is empty")),
+        };
+
+        let mut triangles = vec![];
+
+        loop {
+            let Some((line, text)) = lines.next() else {
+                return Err(error(last_line, "unexpected end of file, missing `endsolid`"));
+            };
+
+            if text.starts_with("endsolid") {
+                break;
+            }
+            if !text.starts_with("facet") {
+                return Err(error(line, "expected `facet`"));
+            }
+
+            let mut expect = |keyword: &str| match lines.next() {
+                Some((l, t)) if t.starts_with(keyword) => Ok((l, t)),
+                Some((l, _)) => Err(error(l, &format!("expected `{keyword}`"))),
+                None => Err(error(line, "unexpected end of file inside facet")),
+            };
+
+            expect("outer loop")?;
+
+            let mut vertices = [Vec3::new(0.0, 0.0, 0.0); 3];
+            for v in vertices.iter_mut() {
+                let (l, t) = expect("vertex")?;
+                let coords = t
+                    .split_whitespace()
+                    .skip(1)
+                    .map(|c| c.parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| error(l, "invalid vertex coordinate"))?;
+
+                if coords.len() != 3 {
+                    return Err(error(l, "vertex needs 3 coordinates"));
+                }
+
+                *v = Vec3::new(coords[0], coords[1], coords[2]);
+            }
+
+            expect("endloop")?;
+            let (l, _) = expect("endfacet")?;
+            last_line = l;
+
+            triangles.push(Triangle::new(vertices));
+        }
+
+        Ok(Mesh::new().set_triangles(triangles).build())
+    }
+
+    /// Writes triangles of a Mesh in ASCII form.
+    /// Triangles are written in object space, position and rotation of the Mesh are not applied.
+    pub fn write_ascii(mesh: &Mesh, writer: &mut impl Write) -> Result<(), StlError> {
+        writeln!(writer, "solid rustender")?;
+
+        for t in &mesh.triangles {
+            let n = t.normal();
+            writeln!(writer, "  facet normal {:e} {:e} {:e}", n.x, n.y, n.z)?;
+            writeln!(writer, "    outer loop")?;
+            for v in &t.vertices {
+                writeln!(writer, "      vertex {:e} {:e} {:e}", v.x, v.y, v.z)?;
+            }
+            writeln!(writer, "    endloop")?;
+            writeln!(writer, "  endfacet")?;
+        }
+
+        writeln!(writer, "endsolid rustender")?;
+
+        Ok(())
+    }
+
+    /// Writes triangles of a Mesh in binary form.
+    /// Triangles are written in object space, position and rotation of the Mesh are not applied.
+    pub fn write_binary(mesh: &Mesh, writer: &mut impl Write) -> Result<(), StlError> {
+        let mut header = [0u8; HEADER_SIZE];
+        header[..14].copy_from_slice(b"rustender mesh");
+
+        writer.write_all(&header)?;
+        writer.write_all(&(mesh.triangles.len() as u32).to_le_bytes())?;
+
+        for t in &mesh.triangles {
+            for v in std::iter::once(t.normal()).chain(t.vertices) {
+                for c in [v.x, v.y, v.z] {
+                    writer.write_all(&(c as f32).to_le_bytes())?;
+                }
+            }
+            writer.write_all(&0u16.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-use std::ops::{Add, Index, Mul, Sub};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::Vec3;
 
 /// struct representing a mathematical matrix of multiple values
 ///
@@ -35,8 +37,148 @@ use std::ops::{Add, Index, Mul, Sub};
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub struct Matrix<T, const R: usize, const C: usize>(pub [[T; C]; R]);
 
-impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+impl<T: Copy + Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Swaps rows and columns of the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use rustender::engine::math::matrices::Matrix;
+    ///
+    /// let matrix = Matrix([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ]);
+    ///
+    /// assert_eq!(matrix.transpose(), Matrix([
+    ///     [1, 4],
+    ///     [2, 5],
+    ///     [3, 6]
+    /// ]));
+    /// ```
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        let mut result = Matrix([[T::default(); R]; C]);
+
+        for i in 0..R {
+            for j in 0..C {
+                result.0[j][i] = self.0[i][j];
+            }
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> Matrix<f64, N, N> {
+    /// Square matrix with ones on the diagonal, multiplying by it changes nothing
+    pub fn identity() -> Self {
+        let mut result = Matrix([[0.0; N]; N]);
+
+        for i in 0..N {
+            result.0[i][i] = 1.0;
+        }
+
+        result
+    }
+
+    /// Calculates the determinant using Gaussian elimination
+    ///
+    /// # Examples
+    /// ```
+    /// use rustender::engine::math::matrices::Matrix;
+    ///
+    /// let matrix = Matrix([
+    ///     [2.0, 0.0],
+    ///     [1.0, 3.0]
+    /// ]);
+    ///
+    /// assert_eq!(matrix.determinant(), 6.0);
+    /// ```
+    #[allow(clippy::needless_range_loop)]
+    pub fn determinant(&self) -> f64 {
+        let mut m = self.0;
+        let mut det = 1.0;
+
+        for col in 0..N {
+            let pivot = (col..N)
+                .max_by(|a, b| m[*a][col].abs().total_cmp(&m[*b][col].abs()))
+                .unwrap_or(col);
+
+            if m[pivot][col] == 0.0 {
+                return 0.0;
+            }
+
+            if pivot != col {
+                m.swap(pivot, col);
+                det = -det;
+            }
+
+            det *= m[col][col];
+
+            for row in col + 1..N {
+                let factor = m[row][col] / m[col][col];
+                for k in col..N {
+                    m[row][k] -= factor * m[col][k];
+                }
+            }
+        }
+
+        det
+    }
+
+    /// Calculates the inverse matrix using Gauss-Jordan elimination
+    ///
+    /// # Returns
+    /// inverse matrix or None if the matrix is singular
+    ///
+    /// # Examples
+    /// ```
+    /// use rustender::engine::math::matrices::Matrix;
+    ///
+    /// let matrix = Matrix([
+    ///     [2.0, 0.0],
+    ///     [0.0, 4.0]
+    /// ]);
+    ///
+    /// assert_eq!(matrix.inverse(), Some(Matrix([
+    ///     [0.5, 0.0],
+    ///     [0.0, 0.25]
+    /// ])));
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let mut m = self.0;
+        let mut inv = Self::identity().0;
+
+        for col in 0..N {
+            let pivot = (col..N).max_by(|a, b| m[*a][col].abs().total_cmp(&m[*b][col].abs()))?;
+
+            if m[pivot][col].abs() < f64::EPSILON {
+                return None;
+            }
+
+            m.swap(pivot, col);
+            inv.swap(pivot, col);
+
+            let p = m[col][col];
+            for k in 0..N {
+                m[col][k] /= p;
+                inv[col][k] /= p;
+            }
+
+            for row in 0..N {
+                if row == col {
+                    continue;
+                }
 
+                let factor = m[row][col];
+                for k in 0..N {
+                    m[row][k] -= factor * m[col][k];
+                    inv[row][k] -= factor * inv[col][k];
+                }
+            }
+        }
+
+        Some(Matrix(inv))
+    }
 }
 
 impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
@@ -65,6 +207,12 @@ impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C
     }
 }
 
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[r][c]
+    }
+}
+
 /// Adding two matrices of same type using + operator
 ///
 /// # Examples
@@ -183,3 +331,73 @@ impl<T: Add<Output = T> + Mul<Output = T> + Copy + Default, const R: usize, cons
         result
     }
 }
+
+/// Multiplying a 3D vector by a 3x3 matrix
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// // returns Vec3 (0.0, 1.0, 0.0) up to rounding
+/// let result = rotation_matrix_z_3d(std::f64::consts::FRAC_PI_2) * Vec3::new(1.0, 0.0, 0.0);
+/// ```
+impl Mul<Vec3> for Matrix<f64, 3, 3> {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let m = self.0;
+
+        Vec3::new(
+            m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z,
+            m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z,
+            m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z,
+        )
+    }
+}
+
+/// Embedding a 3x3 linear transform into a homogeneous 4x4 matrix
+impl From<Matrix<f64, 3, 3>> for Matrix<f64, 4, 4> {
+    fn from(value: Matrix<f64, 3, 3>) -> Self {
+        let m = value.0;
+
+        Matrix([
+            [m[0][0], m[0][1], m[0][2], 0.0],
+            [m[1][0], m[1][1], m[1][2], 0.0],
+            [m[2][0], m[2][1], m[2][2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+/// Transforming a point by a homogeneous 4x4 matrix.
+/// The point is treated as having `w = 1` and the result is divided by the resulting `w`.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let translation = Matrix([
+///     [1.0, 0.0, 0.0, 1.0],
+///     [0.0, 1.0, 0.0, 2.0],
+///     [0.0, 0.0, 1.0, 3.0],
+///     [0.0, 0.0, 0.0, 1.0]
+/// ]);
+///
+/// // returns Vec3 (1.0, 2.0, 3.0)
+/// let result = translation * Vec3::new(0.0, 0.0, 0.0);
+/// ```
+impl Mul<Vec3> for Matrix<f64, 4, 4> {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let m = self.0;
+        let x = m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z + m[0][3];
+        let y = m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z + m[1][3];
+        let z = m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z + m[2][3];
+        let w = m[3][0] * rhs.x + m[3][1] * rhs.y + m[3][2] * rhs.z + m[3][3];
+
+        if w != 0.0 && w != 1.0 {
+            Vec3::new(x / w, y / w, z / w)
+        } else {
+            Vec3::new(x, y, z)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod matrices;
 mod special_matrices;
+mod quaternion;
 
 pub use matrices::*;
 pub use special_matrices::*;
+pub use quaternion::*;
@@ -0,0 +1,267 @@
+use std::ops::Mul;
+
+use crate::prelude::*;
+
+/// Struct representing a rotation in 3D space as a unit quaternion.
+/// Unlike Euler angles it does not suffer from gimbal lock and can be smoothly interpolated.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let start = Quaternion::identity();
+/// let end = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), std::f64::consts::PI);
+///
+/// let halfway = start.slerp(end, 0.5);
+///
+/// let rotated = halfway.rotate(Vec3::new(0.0, 0.0, 1.0));
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    /// Scalar part of the quaternion
+    pub w: f64,
+    /// X component of the vector part
+    pub x: f64,
+    /// Y component of the vector part
+    pub y: f64,
+    /// Z component of the vector part
+    pub z: f64,
+}
+
+impl Quaternion {
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Quaternion representing no rotation
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a rotation around an arbitrary axis
+    ///
+    /// # Parameters
+    /// - `axis`: axis of the rotation, does not need to be normalized
+    /// - `angle`: angle of the rotation in radians
+    ///
+    /// # Returns
+    /// the rotation, or the identity when `axis` has no length
+    pub fn from_axis_angle(axis: Vec3, angle: f64) -> Self {
+        let Some(axis) = axis.try_normalize() else {
+            return Self::identity();
+        };
+        let (sin, cos) = (angle / 2.0).sin_cos();
+
+        Self::new(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    /// Creates a quaternion from Euler angles
+    ///
+    /// # Parameters
+    /// - `rotation`: angles around each axis in radians
+    /// - `order`: order in which the single axis rotations are applied
+    pub fn from_rotation(rotation: &Rotation, order: EulerOrder) -> Self {
+        let angles = [rotation.x, rotation.y, rotation.z];
+        let axes = [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)];
+
+        order
+            .axes()
+            .iter()
+            .fold(Self::identity(), |q, &a| Self::from_axis_angle(axes[a], angles[a]) * q)
+    }
+
+    /// Converts the quaternion into Euler angles
+    ///
+    /// # Parameters
+    /// - `order`: order in which the single axis rotations are applied
+    ///
+    /// # Returns
+    /// Rotation producing the same orientation when applied in the given order
+    pub fn to_rotation(&self, order: EulerOrder) -> Rotation {
+        let m = self.to_matrix();
+        let [i, j, k] = order.axes();
+        let sign = if order.is_cyclic() { 1.0 } else { -1.0 };
+
+        let sin_b = (-sign * m[(k, i)]).clamp(-1.0, 1.0);
+        let b = sin_b.asin();
+
+        let (a, c) = if sin_b.abs() > 1.0 - 1e-9 {
+            // Gimbal lock, first and last rotation share the same axis so the last one is dropped
+            ((-sign * m[(j, k)]).atan2(m[(j, j)]), 0.0)
+        } else {
+            (
+                (sign * m[(k, j)]).atan2(m[(k, k)]),
+                (sign * m[(j, i)]).atan2(m[(i, i)]),
+            )
+        };
+
+        let mut angles = [0.0; 3];
+        angles[i] = a;
+        angles[j] = b;
+        angles[k] = c;
+
+        Rotation::new(angles[0], angles[1], angles[2])
+    }
+
+    /// Converts the quaternion into a rotation matrix
+    pub fn to_matrix(&self) -> Matrix<f64, 3, 3> {
+        let Self { w, x, y, z } = self.normalize();
+
+        Matrix([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+            [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+            [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+        ])
+    }
+
+    /// Creates a quaternion from a rotation matrix
+    pub fn from_matrix(m: &Matrix<f64, 3, 3>) -> Self {
+        let trace = m[(0, 0)] + m[(1, 1)] + m[(2, 2)];
+
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                s / 4.0,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m[(0, 0)] > m[(1, 1)] && m[(0, 0)] > m[(2, 2)] {
+            let s = (1.0 + m[(0, 0)] - m[(1, 1)] - m[(2, 2)]).sqrt() * 2.0;
+            Self::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                s / 4.0,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m[(1, 1)] > m[(2, 2)] {
+            let s = (1.0 + m[(1, 1)] - m[(0, 0)] - m[(2, 2)]).sqrt() * 2.0;
+            Self::new(
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                s / 4.0,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = (1.0 + m[(2, 2)] - m[(0, 0)] - m[(1, 1)]).sqrt() * 2.0;
+            Self::new(
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                s / 4.0,
+            )
+        };
+
+        q.normalize()
+    }
+
+    pub fn dot(&self, rhs: Quaternion) -> f64 {
+        self.w * rhs.w + self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn length(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// Scales the quaternion to unit length, zero quaternion becomes the identity
+    pub fn normalize(&self) -> Self {
+        let len = self.length();
+        if len == 0.0 {
+            return Self::identity();
+        }
+
+        Self::new(self.w / len, self.x / len, self.y / len, self.z / len)
+    }
+
+    /// Quaternion with negated vector part, for unit quaternions it is the opposite rotation
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Rotates a vector by the quaternion
+    pub fn rotate(&self, v: Vec3) -> Vec3 {
+        let q = self.normalize();
+        let p = q * Quaternion::new(0.0, v.x, v.y, v.z) * q.conjugate();
+
+        Vec3::new(p.x, p.y, p.z)
+    }
+
+    /// Spherical linear interpolation taking the shortest path between two orientations
+    ///
+    /// # Parameters
+    /// - `to`: orientation reached at `t = 1`
+    /// - `t`: interpolation factor from 0 to 1
+    pub fn slerp(&self, to: Quaternion, t: f64) -> Self {
+        let from = self.normalize();
+        let mut to = to.normalize();
+        let mut cos = from.dot(to);
+
+        if cos < 0.0 {
+            to = Self::new(-to.w, -to.x, -to.y, -to.z);
+            cos = -cos;
+        }
+
+        let (a, b) = if cos > 1.0 - 1e-6 {
+            // Nearly identical orientations, linear interpolation is precise enough
+            (1.0 - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        Self::new(
+            from.w * a + to.w * b,
+            from.x * a + to.x * b,
+            from.y * a + to.y * b,
+            from.z * a + to.z * b,
+        )
+        .normalize()
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Composing two rotations, the right hand side is applied first
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl From<Quaternion> for Matrix<f64, 3, 3> {
+    fn from(value: Quaternion) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl From<Matrix<f64, 3, 3>> for Quaternion {
+    fn from(value: Matrix<f64, 3, 3>) -> Self {
+        Quaternion::from_matrix(&value)
+    }
+}
+
+/// Converting Euler angles applied in the default `XYZ` order
+impl From<Rotation> for Quaternion {
+    fn from(value: Rotation) -> Self {
+        Quaternion::from_rotation(&value, EulerOrder::XYZ)
+    }
+}
+
+/// Converting into Euler angles applied in the default `XYZ` order
+impl From<Quaternion> for Rotation {
+    fn from(value: Quaternion) -> Self {
+        value.to_rotation(EulerOrder::XYZ)
+    }
+}
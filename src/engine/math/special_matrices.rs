@@ -1,4 +1,4 @@
-use crate::engine::math::Matrix;
+use crate::{Rotation, Vec3, engine::math::Matrix};
 
 /// Matrix for rotating point in 3D space
 pub fn rotation_matrix_x_3d(angle: f64) -> Matrix<f64, 3, 3> {
@@ -25,6 +25,98 @@ pub fn rotation_matrix_z_3d(angle: f64) -> Matrix<f64, 3, 3> {
     ])
 }
 
+/// Matrix combining rotations around all three axes.
+/// Rotation is applied around the X axis first, then Y and then Z.
+pub fn rotation_matrix_3d(rotation: &Rotation) -> Matrix<f64, 3, 3> {
+    rotation_matrix_z_3d(rotation.z) * rotation_matrix_y_3d(rotation.y) * rotation_matrix_x_3d(rotation.x)
+}
+
+/// Homogeneous matrix moving points from object space to world space.
+/// Points are rotated around the object origin first and then translated by `position`.
+pub fn model_matrix_3d(rotation: &Rotation, position: Vec3) -> Matrix<f64, 4, 4> {
+    translation_matrix_3d(position) * Matrix::from(rotation_matrix_3d(rotation))
+}
+
+/// Homogeneous matrix moving points from world space to the space of a viewer.
+/// It is the inverse of `model_matrix_3d` for the same rotation and position.
+pub fn view_matrix_3d(rotation: &Rotation, position: Vec3) -> Matrix<f64, 4, 4> {
+    let r = rotation_matrix_x_3d(-rotation.x) * rotation_matrix_y_3d(-rotation.y) * rotation_matrix_z_3d(-rotation.z);
+    let t = |row: usize| -(r[(row, 0)] * position.x + r[(row, 1)] * position.y + r[(row, 2)] * position.z);
+
+    Matrix([
+        [r[(0, 0)], r[(0, 1)], r[(0, 2)], t(0)],
+        [r[(1, 0)], r[(1, 1)], r[(1, 2)], t(1)],
+        [r[(2, 0)], r[(2, 1)], r[(2, 2)], t(2)],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
+/// Homogeneous matrix moving points by an offset
+pub fn translation_matrix_3d(offset: Vec3) -> Matrix<f64, 4, 4> {
+    Matrix([
+        [1.0, 0.0, 0.0, offset.x],
+        [0.0, 1.0, 0.0, offset.y],
+        [0.0, 0.0, 1.0, offset.z],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
+/// Homogeneous matrix scaling points by a factor on each axis
+pub fn scale_matrix_3d(scale: Vec3) -> Matrix<f64, 4, 4> {
+    Matrix([
+        [scale.x, 0.0, 0.0, 0.0],
+        [0.0, scale.y, 0.0, 0.0],
+        [0.0, 0.0, scale.z, 0.0],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
+/// Perspective projection matrix for a camera looking along the positive Z axis.
+/// Visible points are mapped into X and Y from -1 to 1 and Z from 0 (near) to 1 (far).
+///
+/// # Parameters
+/// - `fov`: vertical field of view in radians
+/// - `aspect`: width of the view divided by its height
+/// - `near`: distance of the near clipping plane
+/// - `far`: distance of the far clipping plane
+pub fn perspective_matrix_3d(fov: f64, aspect: f64, near: f64, far: f64) -> Matrix<f64, 4, 4> {
+    let f = 1.0 / (fov / 2.0).tan();
+    let depth = far / (far - near);
+
+    Matrix([
+        [f / aspect, 0.0, 0.0, 0.0],
+        [0.0, f, 0.0, 0.0],
+        [0.0, 0.0, depth, -near * depth],
+        [0.0, 0.0, 1.0, 0.0]
+    ])
+}
+
+/// Orthographic projection matrix for a camera looking along the positive Z axis.
+/// Points of the given box are mapped into X and Y from -1 to 1 and Z from 0 (near) to 1 (far).
+pub fn orthographic_matrix_3d(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Matrix<f64, 4, 4> {
+    Matrix([
+        [2.0 / (right - left), 0.0, 0.0, -(right + left) / (right - left)],
+        [0.0, 2.0 / (top - bottom), 0.0, -(top + bottom) / (top - bottom)],
+        [0.0, 0.0, 1.0 / (far - near), -near / (far - near)],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
+/// View matrix of a viewer at `eye` looking at `target`.
+/// The viewer looks along the positive Z axis and `up` becomes its positive Y axis.
+pub fn look_at_matrix_3d(eye: Vec3, target: Vec3, up: Vec3) -> Matrix<f64, 4, 4> {
+    let forward = (target - eye).normalize();
+    let right = up.cross(forward).normalize();
+    let up = forward.cross(right);
+
+    Matrix([
+        [right.x, right.y, right.z, -right.dot(eye)],
+        [up.x, up.y, up.z, -up.dot(eye)],
+        [forward.x, forward.y, forward.z, -forward.dot(eye)],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
 /// Matrix for rotating points in 2D space counter clockwise
 pub fn rotation_matrix_2d(angle: f64) -> Matrix<f64, 2, 2> {
     Matrix([
@@ -1,3 +1,4 @@
 pub mod math;
 pub mod graphics;
 pub mod rendering;
+pub mod io;
@@ -1,16 +1,15 @@
+use std::fmt::{self, Display};
 use std::ops::{Index, IndexMut};
-use crossterm::cursor::{MoveTo};
-use crossterm::style::Print;
-use std::io::{Write, stdout};
+use std::io;
 
-use crossterm::queue;
+use crate::{Backend, Color, ColorMode, TerminalBackend};
 
 #[allow(dead_code)]
 pub const ASCII_BRIGHTNESS: &[u8] = b" .:-=+*#%@";
 
 
 pub fn ascii_from_intensity(intensity: f64) -> char {
-    let idx = (intensity * (ASCII_BRIGHTNESS.len() as f64 - 1.0)).round() as usize;
+    let idx = (intensity.clamp(0.0, 1.0) * (ASCII_BRIGHTNESS.len() as f64 - 1.0)).round() as usize;
     ASCII_BRIGHTNESS[idx] as char
 }
 
@@ -18,21 +17,46 @@ pub fn ascii_from_intensity(intensity: f64) -> char {
 #[derive(Debug, Clone, PartialEq)]
 pub struct AsciiBuffer {
     pub buffer: Vec<char>,
+    /// Distance from the camera of every char in `buffer`, used for the depth test
+    pub depth: Vec<f64>,
+    /// Color of every char in `buffer`, None keeps the terminal default
+    pub foreground: Vec<Option<Color>>,
+    /// Color behind every char in `buffer`, None keeps the terminal default
+    pub background: Vec<Option<Color>>,
     pub chunk_width: u32
 }
 
 impl AsciiBuffer {
     pub fn new(chunk_width: u32, buff: Vec<char>) -> Self {
         Self {
+            depth: vec![f64::INFINITY; buff.len()],
+            foreground: vec![None; buff.len()],
+            background: vec![None; buff.len()],
             buffer: buff,
             chunk_width
         }
     }
 
     pub fn set(& mut self, buff: Vec<char>) {
+        self.depth = vec![f64::INFINITY; buff.len()];
+        self.foreground = vec![None; buff.len()];
+        self.background = vec![None; buff.len()];
         self.buffer = buff;
     }
 
+    /// Resets every char to empty space without colors and every depth to infinity
+    pub fn clear(&mut self) {
+        self.buffer.fill(' ');
+        self.depth.fill(f64::INFINITY);
+        self.foreground.fill(None);
+        self.background.fill(None);
+    }
+
+    /// Number of rows, zero for a buffer without columns
+    pub fn height(&self) -> usize {
+        self.buffer.len().checked_div(self.chunk_width as usize).unwrap_or(0)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.buffer.is_empty()
     }
@@ -42,23 +66,119 @@ impl AsciiBuffer {
     }
 
     pub fn chunk(&self, start: usize, end: usize) -> Self {
-        Self::new(self.chunk_width, self.buffer[start..end].to_vec().clone())
+        Self {
+            buffer: self.buffer[start..end].to_vec(),
+            depth: self.depth[start..end].to_vec(),
+            foreground: self.foreground[start..end].to_vec(),
+            background: self.background[start..end].to_vec(),
+            chunk_width: self.chunk_width
+        }
     }
 
+    /// Prints the buffer once using colors detected from the terminal environment.
+    /// Every call redraws all cells, to show frames in a loop keep a backend and use `present_to`.
+    ///
+    /// # Panics
+    /// when writing to the standard output fails
     pub fn print(&self) {
-        for i in 0..self.buffer.len() {
-            let x = (i % self.chunk_width as usize) as u16;
-            let y = (i / self.chunk_width as usize) as u16;
+        self.print_with(ColorMode::detect());
+    }
 
-            queue!(stdout(), MoveTo(x, y), Print(self.buffer[i])).expect("Failed to print to console");
-        }
+    /// Prints every char of the buffer once, colors are converted for the given mode.
+    /// Every call redraws all cells, to show frames in a loop keep a backend and use `present_to`.
+    ///
+    /// # Panics
+    /// when writing to the standard output fails
+    pub fn print_with(&self, mode: ColorMode) {
+        self.present_to(&mut TerminalBackend::new(mode).with_session(false)).expect("Failed to print to console");
+    }
 
-        stdout().flush().expect("Failed to refresh buffer");
+    /// Shows the buffer on any backend, e.g. a terminal behind a writer or a `MemoryBackend`.
+    /// A `TerminalBackend` kept between calls writes only cells which changed since the previous buffer.
+    ///
+    /// # Parameters
+    /// - `backend`: output receiving the buffer
+    ///
+    /// # Examples
+    /// ```
+    /// use rustender::prelude::*;
+    ///
+    /// let mut backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+    /// let mut buffer = AsciiBuffer::new(2, vec![' '; 4]);
+    ///
+    /// buffer.present_to(&mut backend).unwrap();
+    /// buffer[(1, 1)] = '#';
+    /// buffer.present_to(&mut backend).unwrap();
+    /// ```
+    pub fn present_to(&self, backend: &mut impl Backend) -> io::Result<()> {
+        backend.present(self)
     }
 
     pub fn update_at(&mut self, i: usize, j: usize, value: char) {
         self.buffer[i + (j * self.chunk_width as usize)] = value;
     }
+
+    /// Writes a char only if it is closer to the camera than the one already stored
+    ///
+    /// # Parameters
+    /// - `i`: column of the char
+    /// - `j`: row of the char
+    /// - `depth`: distance of the char from the camera
+    /// - `value`: char to be written
+    ///
+    /// # Returns
+    /// true if the char passed the depth test and was written
+    pub fn update_at_depth(&mut self, i: usize, j: usize, depth: f64, value: char) -> bool {
+        self.update_colored_at_depth(i, j, depth, value, None, None)
+    }
+
+    /// Writes a char with its colors only if it is closer to the camera than the one already stored
+    ///
+    /// # Parameters
+    /// - `i`: column of the char
+    /// - `j`: row of the char
+    /// - `depth`: distance of the char from the camera
+    /// - `value`: char to be written
+    /// - `foreground`: color of the char
+    /// - `background`: color behind the char
+    ///
+    /// # Returns
+    /// true if the char passed the depth test and was written
+    pub fn update_colored_at_depth(
+        &mut self,
+        i: usize,
+        j: usize,
+        depth: f64,
+        value: char,
+        foreground: Option<Color>,
+        background: Option<Color>,
+    ) -> bool {
+        let idx = i + (j * self.chunk_width as usize);
+        if depth >= self.depth[idx] {
+            return false;
+        }
+
+        self.depth[idx] = depth;
+        self.buffer[idx] = value;
+        self.foreground[idx] = foreground;
+        self.background[idx] = background;
+
+        true
+    }
+}
+
+/// Chars of the buffer with rows separated by new lines
+impl Display for AsciiBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.buffer.chunks(self.chunk_width.max(1) as usize).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|c| write!(f, "{c}"))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Index<(usize, usize)> for AsciiBuffer {
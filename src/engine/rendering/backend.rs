@@ -0,0 +1,279 @@
+use std::{
+    io::{self, Stdout, Write, stdout},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crossterm::{cursor, event, queue};
+
+use crate::prelude::*;
+
+/// Output a Renderer shows its frames on
+#[allow(dead_code)]
+pub trait Backend {
+    /// Prepares the output before the first frame
+    fn init(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Shows a finished frame
+    fn present(&mut self, frame: &AsciiBuffer) -> io::Result<()>;
+
+    /// Returns the output into its original state after the last frame
+    fn restore(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Number of columns and rows of the output, None when it has no fixed size
+    fn size(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Forgets what was shown so the next frame is written completely
+    fn invalidate(&mut self) {}
+
+    /// Whether the user asked to quit through the output, e.g. by Ctrl+C in a terminal
+    fn quit_requested(&mut self) -> bool {
+        false
+    }
+}
+
+/// Draws frames into a terminal through crossterm, writing only changed cells.
+/// The terminal of the process is taken over by a `TerminalSession` between `init` and `restore`.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let backend = TerminalBackend::new(ColorMode::detect());
+/// let renderer = Renderer::new().width(80.0).height(24.0).backend(backend).build();
+/// ```
+#[allow(dead_code)]
+pub struct TerminalBackend<W: Write = Stdout> {
+    out: W,
+    presenter: Presenter,
+    /// Whether `init` takes over the terminal
+    use_session: bool,
+    session: Option<TerminalSession>,
+    /// Whether `init` hid the cursor, so `restore` knows there is something to undo
+    initialized: bool,
+    /// Columns and rows given by the caller, overriding the size of the process terminal
+    size: Option<(usize, usize)>,
+}
+
+impl TerminalBackend {
+    /// Terminal of the process on the standard output
+    pub fn new(color_mode: ColorMode) -> Self {
+        Self::with_writer(stdout(), color_mode).with_session(true)
+    }
+}
+
+impl<W: Write> TerminalBackend<W> {
+    /// Terminal behind any writer, e.g. a pseudo terminal of an embedding application.
+    /// The terminal of the process is left untouched unless enabled by `with_session`.
+    pub fn with_writer(out: W, color_mode: ColorMode) -> Self {
+        Self { out, presenter: Presenter::new(color_mode), use_session: false, session: None, initialized: false, size: None }
+    }
+
+    /// Sets whether the terminal of the process is switched to the alternate screen and raw mode while drawing
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_session(mut self, use_session: bool) -> Self {
+        self.use_session = use_session;
+
+        self
+    }
+
+    /// Sets the size of the terminal behind the writer, used by a Renderer built without an explicit size
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_size(mut self, columns: usize, rows: usize) -> Self {
+        self.size = Some((columns, rows));
+
+        self
+    }
+
+    /// Writes every cell of every frame instead of only the changed ones
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_full_redraw(mut self, full_redraw: bool) -> Self {
+        self.presenter = self.presenter.with_full_redraw(full_redraw);
+
+        self
+    }
+
+    /// Returns the writer
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Backend for TerminalBackend<W> {
+    fn init(&mut self) -> io::Result<()> {
+        if self.use_session && self.session.is_none() {
+            self.session = Some(TerminalSession::begin()?);
+        }
+
+        self.presenter.invalidate();
+        queue!(self.out, cursor::Hide)?;
+        self.initialized = true;
+        self.out.flush()
+    }
+
+    fn present(&mut self, frame: &AsciiBuffer) -> io::Result<()> {
+        self.presenter.present(frame, &mut self.out).map(|_| ())
+    }
+
+    fn restore(&mut self) -> io::Result<()> {
+        // Without a successful init the terminal may belong to another session, which keeps its cursor hidden
+        if !std::mem::take(&mut self.initialized) {
+            return Ok(());
+        }
+
+        queue!(self.out, cursor::Show)?;
+        self.out.flush()?;
+        self.session = None;
+
+        Ok(())
+    }
+
+    fn size(&self) -> Option<(usize, usize)> {
+        // The size of the process terminal says nothing about a terminal behind another writer
+        match (self.size, self.use_session) {
+            (Some(size), _) => Some(size),
+            (None, true) => crossterm::terminal::size().ok().map(|(columns, rows)| (columns as usize, rows as usize)),
+            (None, false) => None,
+        }
+    }
+
+    fn invalidate(&mut self) {
+        self.presenter.invalidate();
+    }
+
+    fn quit_requested(&mut self) -> bool {
+        // Input is only read while the session holds the terminal in raw mode
+        if self.session.is_none() {
+            return false;
+        }
+
+        while event::poll(Duration::ZERO).unwrap_or(false) {
+            match event::read() {
+                Ok(e) if TerminalSession::is_interrupt(&e) => return true,
+                Ok(event::Event::Resize(..)) => self.presenter.invalidate(),
+                Ok(_) => {}
+                Err(_) => return false,
+            }
+        }
+
+        false
+    }
+}
+
+/// Writes frames as plain text lines into any writer, e.g. a log file.
+/// Frames are separated by an empty line.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let mut backend = WriterBackend::new(Vec::new());
+/// backend.present(&AsciiBuffer::new(2, vec!['a', 'b', 'c', 'd'])).unwrap();
+///
+/// assert_eq!(backend.into_inner(), b"ab\ncd\n\n");
+/// ```
+#[allow(dead_code)]
+pub struct WriterBackend<W: Write> {
+    out: W,
+}
+
+impl<W: Write> WriterBackend<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    /// Returns the writer
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Backend for WriterBackend<W> {
+    fn present(&mut self, frame: &AsciiBuffer) -> io::Result<()> {
+        for line in frame_lines(frame) {
+            writeln!(self.out, "{line}")?;
+        }
+
+        writeln!(self.out)?;
+        self.out.flush()
+    }
+}
+
+/// Keeps the last frame as a grid of strings, e.g. for tests.
+/// Clones share the same output, so a clone can be kept to inspect frames of a Renderer.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let backend = MemoryBackend::new();
+/// let mut shared = backend.clone();
+///
+/// shared.present(&AsciiBuffer::new(2, vec!['a', 'b', 'c', 'd'])).unwrap();
+///
+/// assert_eq!(backend.lines(), vec!["ab", "cd"]);
+/// assert_eq!(backend.frames(), 1);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBackend {
+    state: Arc<Mutex<MemoryState>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    lines: Vec<String>,
+    frames: usize,
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rows of the last frame
+    pub fn lines(&self) -> Vec<String> {
+        self.state.lock().unwrap().lines.clone()
+    }
+
+    /// Last frame with rows separated by new lines
+    pub fn text(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    /// Number of frames presented so far
+    pub fn frames(&self) -> usize {
+        self.state.lock().unwrap().frames
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn present(&mut self, frame: &AsciiBuffer) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.lines = frame_lines(frame);
+        state.frames += 1;
+
+        Ok(())
+    }
+}
+
+/// Splits chars of a frame into rows
+fn frame_lines(frame: &AsciiBuffer) -> Vec<String> {
+    frame
+        .buffer
+        .chunks(frame.chunk_width.max(1) as usize)
+        .map(|row| row.iter().collect())
+        .collect()
+}
@@ -2,15 +2,26 @@ use crate::prelude::*;
 
 /// Struct for representing camera and FOV in rendering process
 ///
+/// Camera projects perspectively by default, orthographic projection can be selected
+/// through `CameraBuilder::orthographic`.
+///
+/// Field of view is vertical and stored in radians. Horizontal field of view follows
+/// from the aspect ratio of the view, which takes into account that terminal cells
+/// are taller than they are wide.
+///
 /// # Example
 /// ```
 /// use rustender::prelude::*;
 ///
-/// let camera = Camera::new()
+/// let mut camera = Camera::new()
 ///     .location([1.0, 0.0, 0.0].into())
+///     .rotation_y(0.5)
+///     .fov_degrees(75.0)
 ///     .width(80)
 ///     .height(50)
 ///     .build();
+///
+/// camera.set_location([0.0, 0.0, -5.0].into());
 /// ```
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq)]
@@ -23,8 +34,16 @@ pub struct Camera {
     pub height: usize,
     /// Rotation of a camera view by X, Y and Z axis by radians
     rotation: Rotation,
-    /// Field of view of the camera
+    /// Vertical field of view of the camera in radians
     fov: f64,
+    /// Height of a single char cell divided by its width
+    char_aspect: f64,
+    /// Mapping of camera space onto the screen
+    projection: Projection,
+    /// Distance of the near clipping plane, geometry closer to the camera is cut away
+    near: f64,
+    /// Distance of the far clipping plane, geometry further from the camera is cut away
+    far: f64,
 }
 
 impl Camera {
@@ -34,12 +53,203 @@ impl Camera {
             location: None,
             width: None,
             height: None,
+            rotation: None,
             fov: None,
+            char_aspect: None,
+            projection: None,
+            near: None,
+            far: None,
         }
     }
 
+    /// Vertical focal length measured in rows.
+    /// For orthographic projection it is the number of rows per world unit.
     pub fn get_focal_length(&self) -> f64 {
-        (self.height / 2) as f64 / (self.fov / 2.0).tan()
+        match self.projection {
+            Projection::Perspective => (self.height as f64 / 2.0) / (self.fov / 2.0).tan(),
+            Projection::Orthographic { view_height } => self.height as f64 / view_height,
+        }
+    }
+
+    /// Horizontal focal length measured in columns.
+    /// Columns are narrower than rows so the value is scaled by the char aspect.
+    pub fn get_horizontal_focal_length(&self) -> f64 {
+        self.get_focal_length() * self.char_aspect
+    }
+
+    /// Vertical field of view in radians
+    pub fn fov(&self) -> f64 {
+        self.fov
+    }
+
+    /// Vertical field of view in degrees
+    pub fn fov_degrees(&self) -> f64 {
+        self.fov.to_degrees()
+    }
+
+    /// Horizontal field of view in radians, derived from the aspect ratio
+    pub fn horizontal_fov(&self) -> f64 {
+        2.0 * ((self.fov / 2.0).tan() * self.aspect_ratio()).atan()
+    }
+
+    /// Ratio of the physical width and height of the view, corrected by the char aspect
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width as f64 / (self.height as f64 * self.char_aspect)
+    }
+
+    /// Height of a single char cell divided by its width
+    pub fn char_aspect(&self) -> f64 {
+        self.char_aspect
+    }
+
+    /// Mapping of camera space onto the screen
+    pub fn projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// Switches between perspective and orthographic projection
+    ///
+    /// # Panics
+    /// if an orthographic view height is not greater than zero
+    pub fn set_projection(&mut self, projection: Projection) {
+        projection.validate();
+        self.projection = projection;
+    }
+
+    /// Sets vertical field of view in radians
+    pub fn set_fov(&mut self, fov: f64) {
+        self.fov = fov;
+    }
+
+    /// Sets the ratio of char cell height and width of the terminal
+    pub fn set_char_aspect(&mut self, char_aspect: f64) {
+        self.char_aspect = char_aspect;
+    }
+
+    /// Copy of the camera rendering into a grid with more pixels per cell, e.g. for `RenderMode::HalfBlock`
+    ///
+    /// # Parameters
+    /// -`columns`: number of pixels per cell horizontally
+    /// -`rows`: number of pixels per cell vertically
+    ///
+    /// # Returns
+    /// camera with the same view, its size and char aspect adjusted to the pixels
+    pub fn scaled(&self, columns: usize, rows: usize) -> Camera {
+        Camera {
+            width: self.width * columns,
+            height: self.height * rows,
+            char_aspect: self.char_aspect * columns as f64 / rows as f64,
+            ..self.clone()
+        }
+    }
+
+    /// Projects a point in camera space onto the screen
+    ///
+    /// # Parameters
+    /// -`point`: point in camera space in front of the camera
+    ///
+    /// # Returns
+    /// column and row of the point, with the camera looking at the center of the screen
+    pub fn project(&self, point: Vec3) -> Vec2 {
+        let divisor = match self.projection {
+            Projection::Perspective => point.z,
+            Projection::Orthographic { .. } => 1.0,
+        };
+
+        Vec2::new(
+            point.x * self.get_horizontal_focal_length() / divisor + self.width as f64 / 2.0,
+            point.y * self.get_focal_length() / divisor + self.height as f64 / 2.0,
+        )
+    }
+
+    /// Location of the camera in world space
+    pub fn location(&self) -> Vec3 {
+        self.location
+    }
+
+    /// Rotation of the camera by X, Y and Z axis in radians
+    pub fn rotation(&self) -> &Rotation {
+        &self.rotation
+    }
+
+    /// Orientation of the camera as a quaternion
+    pub fn orientation(&self) -> Quaternion {
+        Quaternion::from_rotation(&self.rotation, EulerOrder::XYZ)
+    }
+
+    /// Turns the camera to an orientation given by a quaternion
+    pub fn set_orientation(&mut self, orientation: Quaternion) {
+        self.rotation = orientation.to_rotation(EulerOrder::XYZ);
+    }
+
+    /// Moves the camera to a new location in world space
+    ///
+    /// # Parameters
+    /// -`location`: new location of the camera
+    pub fn set_location(&mut self, location: Vec3) {
+        self.location = location;
+    }
+
+    /// Turns the camera to a new orientation
+    ///
+    /// # Parameters
+    /// -`rotation`: new rotation of the camera by X, Y and Z axis in radians
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Distance of the near clipping plane
+    pub fn near(&self) -> f64 {
+        self.near
+    }
+
+    /// Distance of the far clipping plane
+    pub fn far(&self) -> f64 {
+        self.far
+    }
+
+    /// Builds the volume visible by the camera in camera space.
+    /// Side planes are derived from the field of view, width and height of the camera.
+    ///
+    /// # Returns
+    /// frustum made of near, far, left, right, top and bottom planes
+    pub fn frustum(&self) -> Frustum {
+        let focal_x = self.get_horizontal_focal_length();
+        let focal_y = self.get_focal_length();
+        let half_width = self.width as f64 / 2.0;
+        let half_height = self.height as f64 / 2.0;
+
+        let mut planes = match self.projection {
+            Projection::Perspective => vec![
+                Plane::new(Vec3::new(focal_x, 0.0, half_width).normalize(), 0.0),
+                Plane::new(Vec3::new(-focal_x, 0.0, half_width).normalize(), 0.0),
+                Plane::new(Vec3::new(0.0, focal_y, half_height).normalize(), 0.0),
+                Plane::new(Vec3::new(0.0, -focal_y, half_height).normalize(), 0.0),
+            ],
+            Projection::Orthographic { .. } => vec![
+                Plane::new(Vec3::new(1.0, 0.0, 0.0), half_width / focal_x),
+                Plane::new(Vec3::new(-1.0, 0.0, 0.0), half_width / focal_x),
+                Plane::new(Vec3::new(0.0, 1.0, 0.0), half_height / focal_y),
+                Plane::new(Vec3::new(0.0, -1.0, 0.0), half_height / focal_y),
+            ],
+        };
+
+        planes.insert(0, Plane::new(Vec3::new(0.0, 0.0, 1.0), -self.near));
+
+        if self.far.is_finite() {
+            planes.push(Plane::new(Vec3::new(0.0, 0.0, -1.0), self.far));
+        }
+
+        Frustum::new(planes)
+    }
+
+    /// Builds the matrix moving points from world space into the space of the camera,
+    /// where the camera sits in the origin and looks along the positive Z axis.
+    ///
+    /// # Returns
+    /// homogeneous world-to-camera matrix
+    pub fn view_matrix(&self) -> Matrix<f64, 4, 4> {
+        view_matrix_3d(&self.rotation, self.location)
     }
 }
 
@@ -49,7 +259,12 @@ pub struct CameraBuilder {
     location: Option<Vec3>,
     width: Option<usize>,
     height: Option<usize>,
+    rotation: Option<Rotation>,
     fov: Option<f64>,
+    char_aspect: Option<f64>,
+    projection: Option<Projection>,
+    near: Option<f64>,
+    far: Option<f64>,
 }
 
 impl CameraBuilder {
@@ -57,14 +272,23 @@ impl CameraBuilder {
         let location = self.location.unwrap_or([0.0, 0.0, 0.0].into());
         let width = self.width.unwrap_or(100);
         let height = self.height.unwrap_or(60);
-        let fov = self.fov.unwrap_or(90.0_f64);
+        let rotation = self.rotation.unwrap_or(Rotation::new(0.0, 0.0, 0.0));
+        let fov = self.fov.unwrap_or(90.0_f64.to_radians());
+        let char_aspect = self.char_aspect.unwrap_or(2.0);
+        let projection = self.projection.unwrap_or_default();
+        let near = self.near.unwrap_or(0.1).max(f64::EPSILON);
+        let far = self.far.unwrap_or(f64::INFINITY);
 
         Camera {
             location,
             width,
             height,
-            rotation: Rotation::new(0.0, 0.0, 0.0),
+            rotation,
             fov,
+            char_aspect,
+            projection,
+            near,
+            far,
         }
     }
 
@@ -86,9 +310,114 @@ impl CameraBuilder {
         self
     }
 
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = Some(rotation);
+
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Quaternion) -> Self {
+        self.rotation = Some(orientation.to_rotation(EulerOrder::XYZ));
+
+        self
+    }
+
+    pub fn rotation_x(mut self, angle: f64) -> Self {
+        match &mut self.rotation {
+            Some(r) => r.x = angle,
+            None => {
+                self.rotation = Some(Rotation::new(angle, 0.0, 0.0));
+            }
+        }
+
+        self
+    }
+
+    pub fn rotation_y(mut self, angle: f64) -> Self {
+        match &mut self.rotation {
+            Some(r) => r.y = angle,
+            None => {
+                self.rotation = Some(Rotation::new(0.0, angle, 0.0));
+            }
+        }
+
+        self
+    }
+
+    pub fn rotation_z(mut self, angle: f64) -> Self {
+        match &mut self.rotation {
+            Some(r) => r.z = angle,
+            None => {
+                self.rotation = Some(Rotation::new(0.0, 0.0, angle));
+            }
+        }
+
+        self
+    }
+
+    /// Sets vertical field of view in radians
     pub fn fov(mut self, fov: f64) -> Self {
         self.fov = Some(fov);
 
         self
     }
+
+    /// Sets vertical field of view in radians
+    pub fn fov_radians(self, fov: f64) -> Self {
+        self.fov(fov)
+    }
+
+    /// Sets vertical field of view in degrees
+    pub fn fov_degrees(self, fov: f64) -> Self {
+        self.fov(fov.to_radians())
+    }
+
+    /// Sets the ratio of char cell height and width of the terminal.
+    /// Most terminal fonts have cells about twice as tall as wide, which is the default.
+    pub fn char_aspect(mut self, char_aspect: f64) -> Self {
+        self.char_aspect = Some(char_aspect);
+
+        self
+    }
+
+    /// Sets mapping of camera space onto the screen
+    ///
+    /// # Panics
+    /// if an orthographic view height is not greater than zero
+    pub fn projection(mut self, projection: Projection) -> Self {
+        projection.validate();
+        self.projection = Some(projection);
+
+        self
+    }
+
+    /// Uses perspective projection driven by the field of view
+    pub fn perspective(self) -> Self {
+        self.projection(Projection::Perspective)
+    }
+
+    /// Uses orthographic projection
+    ///
+    /// # Parameters
+    /// -`view_height`: height of the visible volume in world units, must be greater than zero
+    ///
+    /// # Panics
+    /// if `view_height` is not greater than zero
+    pub fn orthographic(self, view_height: f64) -> Self {
+        self.projection(Projection::Orthographic { view_height })
+    }
+
+    /// Sets distance of the near clipping plane, must be greater than zero
+    pub fn near(mut self, near: f64) -> Self {
+        self.near = Some(near);
+
+        self
+    }
+
+    /// Sets distance of the far clipping plane
+    pub fn far(mut self, far: f64) -> Self {
+        self.far = Some(far);
+
+        self
+    }
 }
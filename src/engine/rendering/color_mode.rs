@@ -0,0 +1,130 @@
+use crossterm::style;
+
+use crate::prelude::*;
+
+/// RGB values of the 16 basic terminal colors in the order of `style::Color` variants
+const ANSI_16: [(style::Color, [u8; 3]); 16] = [
+    (style::Color::Black, [0, 0, 0]),
+    (style::Color::DarkRed, [128, 0, 0]),
+    (style::Color::DarkGreen, [0, 128, 0]),
+    (style::Color::DarkYellow, [128, 128, 0]),
+    (style::Color::DarkBlue, [0, 0, 128]),
+    (style::Color::DarkMagenta, [128, 0, 128]),
+    (style::Color::DarkCyan, [0, 128, 128]),
+    (style::Color::Grey, [192, 192, 192]),
+    (style::Color::DarkGrey, [128, 128, 128]),
+    (style::Color::Red, [255, 0, 0]),
+    (style::Color::Green, [0, 255, 0]),
+    (style::Color::Yellow, [255, 255, 0]),
+    (style::Color::Blue, [0, 0, 255]),
+    (style::Color::Magenta, [255, 0, 255]),
+    (style::Color::Cyan, [0, 255, 255]),
+    (style::Color::White, [255, 255, 255]),
+];
+
+/// Channel values of the 6x6x6 color cube of 256 color terminals
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// enum representing how many colors a terminal can show
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let mode = ColorMode::detect();
+///
+/// assert_eq!(ColorMode::Monochrome.convert(Color::new(255, 0, 0)), None);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// 24 bit RGB colors
+    TrueColor,
+    /// xterm palette of 256 colors
+    Ansi256,
+    /// 16 basic colors
+    Ansi16,
+    /// No colors at all, only chars are printed
+    #[default]
+    Monochrome,
+}
+
+impl ColorMode {
+    /// Detects colors supported by the current terminal from the environment
+    /// variables `NO_COLOR`, `COLORTERM` and `TERM`
+    pub fn detect() -> Self {
+        Self::detect_from(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+            std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+        )
+    }
+
+    /// Detects colors supported by a terminal from values of its environment variables
+    ///
+    /// # Parameters
+    /// - `colorterm`: value of `COLORTERM`
+    /// - `term`: value of `TERM`
+    /// - `no_color`: whether `NO_COLOR` is set
+    pub fn detect_from(colorterm: Option<&str>, term: Option<&str>, no_color: bool) -> Self {
+        if no_color {
+            return ColorMode::Monochrome;
+        }
+
+        if let Some("truecolor" | "24bit") = colorterm.map(|c| c.trim().to_ascii_lowercase()).as_deref() {
+            return ColorMode::TrueColor;
+        }
+
+        match term.map(|t| t.trim().to_ascii_lowercase()) {
+            None => ColorMode::Monochrome,
+            Some(t) if t.is_empty() || t == "dumb" => ColorMode::Monochrome,
+            Some(t) if t.contains("truecolor") || t.contains("24bit") || t.contains("direct") => ColorMode::TrueColor,
+            Some(t) if t.contains("256") => ColorMode::Ansi256,
+            Some(_) => ColorMode::Ansi16,
+        }
+    }
+
+    /// Converts a color into the closest terminal color of this mode
+    ///
+    /// # Returns
+    /// None for `Monochrome`
+    pub fn convert(&self, color: Color) -> Option<style::Color> {
+        match self {
+            ColorMode::TrueColor => Some(style::Color::Rgb { r: color.r, g: color.g, b: color.b }),
+            ColorMode::Ansi256 => Some(style::Color::AnsiValue(Self::ansi_256(color))),
+            ColorMode::Ansi16 => ANSI_16
+                .iter()
+                .min_by_key(|(_, rgb)| Self::distance(color, (*rgb).into()))
+                .map(|(c, _)| *c),
+            ColorMode::Monochrome => None,
+        }
+    }
+
+    /// Index of the closest color in the xterm palette, either from the color cube or the grayscale ramp
+    fn ansi_256(color: Color) -> u8 {
+        let level = |channel: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - channel as i32).abs())
+                .unwrap()
+        };
+        let (r, g, b) = (level(color.r), level(color.g), level(color.b));
+        let cube = Color::new(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+
+        let average = (color.r as u32 + color.g as u32 + color.b as u32) / 3;
+        let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_value = 8 + 10 * gray_index;
+        let gray = Color::new(gray_value, gray_value, gray_value);
+
+        if Self::distance(color, gray) < Self::distance(color, cube) {
+            232 + gray_index
+        } else {
+            16 + 36 * r as u8 + 6 * g as u8 + b as u8
+        }
+    }
+
+    fn distance(a: Color, b: Color) -> u32 {
+        let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+
+        d(a.r, b.r) + d(a.g, b.g) + d(a.b, b.b)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+/// enum representing which faces of a Mesh are skipped during rendering
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CullMode {
+    /// Faces turned away from the camera are skipped
+    #[default]
+    Back,
+    /// Faces turned towards the camera are skipped
+    Front,
+    /// Every face is rendered
+    None,
+}
+
+/// enum representing in which order the vertices of a front face are listed
+/// when looking at the face from its front side
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum WindingOrder {
+    Clockwise,
+    #[default]
+    CounterClockwise,
+}
+
+impl WindingOrder {
+    /// Checks whether a triangle shows its front side to the camera
+    ///
+    /// # Parameters
+    /// - `triangle`: triangle in camera space, where the camera sits in the origin
+    /// - `camera`: camera looking at the triangle
+    ///
+    /// # Returns
+    /// true if the front side of the triangle is visible
+    pub fn is_front_facing(&self, triangle: &Triangle, camera: &Camera) -> bool {
+        let v = triangle.vertices;
+        let normal = (v[1] - v[0]).cross(v[2] - v[0]);
+        let towards_camera = normal.dot(camera.projection().view_direction(v[0])) < 0.0;
+
+        match self {
+            WindingOrder::CounterClockwise => towards_camera,
+            WindingOrder::Clockwise => !towards_camera,
+        }
+    }
+}
+
+impl CullMode {
+    /// Checks whether a triangle should be skipped
+    ///
+    /// # Parameters
+    /// - `triangle`: triangle in camera space, where the camera sits in the origin
+    /// - `winding`: winding order of front faces
+    /// - `camera`: camera looking at the triangle
+    ///
+    /// # Returns
+    /// true if the triangle should not be rendered
+    pub fn culls(&self, triangle: &Triangle, winding: WindingOrder, camera: &Camera) -> bool {
+        match self {
+            CullMode::Back => !winding.is_front_facing(triangle, camera),
+            CullMode::Front => winding.is_front_facing(triangle, camera),
+            CullMode::None => false,
+        }
+    }
+}
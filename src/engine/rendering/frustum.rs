@@ -0,0 +1,121 @@
+use crate::prelude::*;
+
+/// Plane splitting space into inside and outside half.
+/// A point is inside when `normal.dot(point) + distance >= 0`.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    /// Unit vector pointing into the inside half of the space
+    pub normal: Vec3,
+    /// Signed distance of the plane from the origin along the normal
+    pub distance: f64,
+}
+
+impl Plane {
+    pub fn new(normal: Vec3, distance: f64) -> Self {
+        Self { normal, distance }
+    }
+
+    /// Signed distance of a point from the plane, positive inside
+    pub fn signed_distance(&self, point: Vec3) -> f64 {
+        self.normal.dot(point) + self.distance
+    }
+}
+
+/// Volume of space visible by a camera, described by planes in camera space
+///
+/// # Example
+/// ```
+/// use rustender::prelude::*;
+///
+/// let camera = Camera::new().build();
+/// let frustum = camera.frustum();
+///
+/// let triangle: Triangle = [[0.0, 0.0, 5.0], [1.0, 0.0, 5.0], [0.0, 1.0, -5.0]].into();
+///
+/// // part behind the near plane is cut away
+/// let clipped = frustum.clip_triangle(&triangle);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frustum {
+    pub planes: Vec<Plane>,
+}
+
+impl Frustum {
+    pub fn new(planes: Vec<Plane>) -> Self {
+        Self { planes }
+    }
+
+    /// Checks whether a triangle lies completely outside of the frustum
+    ///
+    /// # Parameters
+    /// - `triangle`: triangle in camera space
+    ///
+    /// # Returns
+    /// true if all vertices are outside of at least one plane
+    pub fn is_outside(&self, triangle: &Triangle) -> bool {
+        self.planes.iter().any(|p| triangle.vertices.iter().all(|v| p.signed_distance(*v) < 0.0))
+    }
+
+    /// Clips a triangle against every plane of the frustum
+    ///
+    /// # Parameters
+    /// - `triangle`: triangle in camera space
+    ///
+    /// # Returns
+    /// triangles covering the visible part of the input, keeping its winding order and blending its attributes
+    pub fn clip_triangle(&self, triangle: &Triangle) -> Vec<Triangle> {
+        if self.is_outside(triangle) {
+            return vec![];
+        }
+
+        // Every corner carries its barycentric weights so attributes can be blended afterwards
+        let mut polygon: Vec<(Vec3, Vec3)> = vec![
+            (triangle.vertices[0], Vec3::new(1.0, 0.0, 0.0)),
+            (triangle.vertices[1], Vec3::new(0.0, 1.0, 0.0)),
+            (triangle.vertices[2], Vec3::new(0.0, 0.0, 1.0)),
+        ];
+
+        if self.planes.iter().all(|p| triangle.vertices.iter().all(|v| p.signed_distance(*v) >= 0.0)) {
+            return vec![triangle.clone()];
+        }
+
+        for plane in &self.planes {
+            polygon = Self::clip_polygon(&polygon, plane);
+
+            if polygon.len() < 3 {
+                return vec![];
+            }
+        }
+
+        let weights = |(_, w): (Vec3, Vec3)| [w.x, w.y, w.z];
+
+        (1..polygon.len() - 1)
+            .map(|i| triangle.sub_triangle([weights(polygon[0]), weights(polygon[i]), weights(polygon[i + 1])]))
+            .collect()
+    }
+
+    /// Sutherland-Hodgman clipping of a convex polygon against a single plane
+    fn clip_polygon(polygon: &[(Vec3, Vec3)], plane: &Plane) -> Vec<(Vec3, Vec3)> {
+        let mut result = Vec::with_capacity(polygon.len() + 1);
+
+        for i in 0..polygon.len() {
+            let current = polygon[i];
+            let next = polygon[(i + 1) % polygon.len()];
+            let d_current = plane.signed_distance(current.0);
+            let d_next = plane.signed_distance(next.0);
+
+            if d_current >= 0.0 {
+                result.push(current);
+            }
+
+            if (d_current >= 0.0) != (d_next >= 0.0) {
+                let t = d_current / (d_current - d_next);
+                result.push((current.0.lerp(next.0, t), current.1.lerp(next.1, t)));
+            }
+        }
+
+        result
+    }
+}
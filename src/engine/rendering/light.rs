@@ -0,0 +1,236 @@
+use std::ops::{Add, AddAssign};
+
+use crate::prelude::*;
+
+/// How the intensity of a point or spot light falls off with distance:
+/// `1 / (constant + linear * d + quadratic * d²)`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    pub constant: f64,
+    pub linear: f64,
+    pub quadratic: f64,
+}
+
+impl Attenuation {
+    pub fn new(constant: f64, linear: f64, quadratic: f64) -> Self {
+        Self { constant, linear, quadratic }
+    }
+
+    /// Light keeping its intensity at any distance
+    pub fn none() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+
+    /// Fraction of the intensity reaching a point at `distance` from the light
+    pub fn factor(&self, distance: f64) -> f64 {
+        let divisor = self.constant + self.linear * distance + self.quadratic * distance * distance;
+
+        if divisor > 0.0 { (1.0 / divisor).min(1.0) } else { 1.0 }
+    }
+}
+
+impl Default for Attenuation {
+    fn default() -> Self {
+        Self::new(1.0, 0.0, 0.01)
+    }
+}
+
+/// Light received by a point of a surface, split by the lighting term
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Illumination {
+    /// Light coming from every direction
+    pub ambient: f64,
+    /// Light scattered equally in every direction, depends on the angle of the surface
+    pub diffuse: f64,
+    /// Highlight reflected towards the viewer
+    pub specular: f64,
+}
+
+impl Add for Illumination {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            ambient: self.ambient + rhs.ambient,
+            diffuse: self.diffuse + rhs.diffuse,
+            specular: self.specular + rhs.specular,
+        }
+    }
+}
+
+impl AddAssign for Illumination {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Light source of a Scene
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let lights = vec![
+///     Light::ambient(0.1),
+///     Light::directional(Vec3::new(0.0, -1.0, -1.0), 0.6),
+///     Light::point(Vec3::new(2.0, -3.0, 4.0), 1.0),
+///     Light::spot(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 0.2, 0.4, 1.0),
+/// ];
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Light {
+    /// Lights every surface equally
+    Ambient { intensity: f64 },
+    /// Infinitely distant light like the sun
+    Directional {
+        /// Direction pointing towards the light
+        direction: Vec3,
+        intensity: f64,
+    },
+    /// Light shining from a point into every direction
+    Point {
+        position: Vec3,
+        intensity: f64,
+        attenuation: Attenuation,
+    },
+    /// Light shining from a point into a cone
+    Spot {
+        position: Vec3,
+        /// Direction the light is pointed at
+        direction: Vec3,
+        intensity: f64,
+        attenuation: Attenuation,
+        /// Angle from the axis in radians inside which the light is at full intensity
+        inner_angle: f64,
+        /// Angle from the axis in radians outside which the light has no effect
+        outer_angle: f64,
+    },
+}
+
+impl Light {
+    pub fn ambient(intensity: f64) -> Self {
+        Light::Ambient { intensity }
+    }
+
+    /// # Parameters
+    /// - `direction`: direction pointing towards the light, a zero vector gives a light lighting nothing
+    /// - `intensity`: strength of the light, 1 lights a facing surface fully
+    pub fn directional(direction: Vec3, intensity: f64) -> Self {
+        Light::Directional { direction: unit_or_zero(direction), intensity }
+    }
+
+    /// Point light with the default attenuation
+    pub fn point(position: Vec3, intensity: f64) -> Self {
+        Light::Point { position, intensity, attenuation: Attenuation::default() }
+    }
+
+    /// Spot light with the default attenuation
+    ///
+    /// # Parameters
+    /// - `position`: location of the light
+    /// - `direction`: direction the light is pointed at, a zero vector gives a light lighting nothing
+    /// - `inner_angle`: angle from the axis in radians inside which the light is at full intensity
+    /// - `outer_angle`: angle from the axis in radians where the light fades out
+    /// - `intensity`: strength of the light
+    pub fn spot(position: Vec3, direction: Vec3, inner_angle: f64, outer_angle: f64, intensity: f64) -> Self {
+        Light::Spot {
+            position,
+            direction: unit_or_zero(direction),
+            intensity,
+            attenuation: Attenuation::default(),
+            inner_angle: inner_angle.min(outer_angle),
+            outer_angle,
+        }
+    }
+
+    /// Replaces the attenuation of point and spot lights, other lights are returned unchanged
+    pub fn with_attenuation(mut self, value: Attenuation) -> Self {
+        if let Light::Point { attenuation, .. } | Light::Spot { attenuation, .. } = &mut self {
+            *attenuation = value;
+        }
+
+        self
+    }
+
+    /// Moves the light into another space, e.g. from world space into camera space
+    ///
+    /// # Parameters
+    /// - `matrix`: affine transformation applied on positions, directions are only rotated
+    pub fn transform(&self, matrix: &Matrix<f64, 4, 4>) -> Self {
+        let rotate = |d: Vec3| unit_or_zero(*matrix * d - *matrix * Vec3::new(0.0, 0.0, 0.0));
+
+        match self.clone() {
+            Light::Ambient { intensity } => Light::Ambient { intensity },
+            Light::Directional { direction, intensity } => Light::Directional { direction: rotate(direction), intensity },
+            Light::Point { position, intensity, attenuation } => Light::Point { position: *matrix * position, intensity, attenuation },
+            Light::Spot { position, direction, intensity, attenuation, inner_angle, outer_angle } => Light::Spot {
+                position: *matrix * position,
+                direction: rotate(direction),
+                intensity,
+                attenuation,
+                inner_angle,
+                outer_angle,
+            },
+        }
+    }
+
+    /// Light received by a point of a surface using the Blinn-Phong model
+    ///
+    /// # Parameters
+    /// - `point`: lit point, in the same space as the light
+    /// - `normal`: unit normal of the surface at the point
+    /// - `to_viewer`: unit vector from the point towards the viewer
+    /// - `shininess`: exponent of the highlight, larger values give smaller highlights
+    pub fn illuminate(&self, point: Vec3, normal: Vec3, to_viewer: Vec3, shininess: f64) -> Illumination {
+        let (to_light, intensity) = match self {
+            Light::Ambient { intensity } => return Illumination { ambient: *intensity, ..Default::default() },
+            Light::Directional { direction, intensity } => (*direction, *intensity),
+            Light::Point { position, intensity, attenuation } => {
+                let offset = *position - point;
+                (offset.try_normalize().unwrap_or(normal), intensity * attenuation.factor(offset.length()))
+            }
+            Light::Spot { position, direction, intensity, attenuation, inner_angle, outer_angle } => {
+                // A spot pointed nowhere has no cone to shine into
+                if direction.try_normalize().is_none() {
+                    return Illumination::default();
+                }
+
+                let offset = *position - point;
+                let to_light = offset.try_normalize().unwrap_or(normal);
+
+                // Smoothly fades out between the inner and outer cone
+                let cos_angle = (-to_light).dot(*direction);
+                let (cos_inner, cos_outer) = (inner_angle.cos(), outer_angle.cos());
+                let cone = if cos_inner > cos_outer {
+                    ((cos_angle - cos_outer) / (cos_inner - cos_outer)).clamp(0.0, 1.0)
+                } else if cos_angle >= cos_outer {
+                    1.0
+                } else {
+                    0.0
+                };
+
+                (to_light, intensity * attenuation.factor(offset.length()) * cone)
+            }
+        };
+
+        let lambert = normal.dot(to_light);
+        if lambert <= 0.0 || intensity <= 0.0 {
+            return Illumination::default();
+        }
+
+        let specular = (to_light + to_viewer)
+            .try_normalize()
+            .map(|half| normal.dot(half).max(0.0).powf(shininess))
+            .unwrap_or(0.0);
+
+        Illumination { ambient: 0.0, diffuse: intensity * lambert, specular: intensity * specular }
+    }
+}
+
+/// Normalized direction, or a zero vector which lights nothing instead of spreading NaN
+fn unit_or_zero(direction: Vec3) -> Vec3 {
+    direction.try_normalize().unwrap_or(Vec3::new(0.0, 0.0, 0.0))
+}
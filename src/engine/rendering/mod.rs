@@ -3,9 +3,39 @@ mod render_quality;
 mod ascii_buffer;
 mod camera;
 mod transferer;
+mod culling;
+mod transfer_options;
+mod frustum;
+mod projection;
+mod shading_mode;
+mod light;
+mod scene;
+mod color_mode;
+mod render_target;
+mod render_mode;
+mod pixel_buffer;
+mod presenter;
+mod backend;
+mod render_loop;
+mod terminal_session;
 
 pub use renderer::*;
 pub use render_quality::*;
 pub use ascii_buffer::*;
 pub use camera::*;
 pub use transferer::*;
+pub use culling::*;
+pub use transfer_options::*;
+pub use frustum::*;
+pub use projection::*;
+pub use shading_mode::*;
+pub use light::*;
+pub use scene::*;
+pub use color_mode::*;
+pub use render_target::*;
+pub use render_mode::*;
+pub use pixel_buffer::*;
+pub use presenter::*;
+pub use backend::*;
+pub use render_loop::*;
+pub use terminal_session::*;
@@ -0,0 +1,179 @@
+use crate::prelude::*;
+
+/// Thresholds of ordered dithering deciding which braille dots are lit
+const BAYER_4X4: [[f64; 4]; 4] = [
+    [0.0, 8.0, 2.0, 10.0],
+    [12.0, 4.0, 14.0, 6.0],
+    [3.0, 11.0, 1.0, 9.0],
+    [15.0, 7.0, 13.0, 5.0],
+];
+
+/// Bit of each braille dot indexed by its row and column within a cell
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// High resolution buffer of lit pixels, encoded into chars of an AsciiBuffer afterwards
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let mut pixels = PixelBuffer::new(4, 8);
+/// pixels.write(0, 0, 1.0, '@', 1.0, None);
+///
+/// let mut cells = AsciiBuffer::new(2, vec![' '; 4]);
+/// pixels.encode(RenderMode::Braille, &mut cells);
+///
+/// assert_eq!(cells[(0, 0)], '\u{2801}');
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelBuffer {
+    pub width: usize,
+    pub height: usize,
+    /// Distance from the camera of every pixel, infinity where nothing was drawn
+    pub depth: Vec<f64>,
+    /// Char picked by the material of every pixel, used when encoded as `RenderMode::Ascii`
+    pub value: Vec<char>,
+    /// Brightness of every pixel
+    pub intensity: Vec<f64>,
+    /// Lit color of every pixel
+    pub color: Vec<Option<Color>>,
+}
+
+impl PixelBuffer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            depth: vec![f64::INFINITY; width * height],
+            value: vec![' '; width * height],
+            intensity: vec![0.0; width * height],
+            color: vec![None; width * height],
+        }
+    }
+
+    /// Changes the size of the buffer and clears it
+    pub fn resize(&mut self, width: usize, height: usize) {
+        *self = Self::new(width, height);
+    }
+
+    /// Resets every pixel to an empty one
+    pub fn clear(&mut self) {
+        self.depth.fill(f64::INFINITY);
+        self.value.fill(' ');
+        self.intensity.fill(0.0);
+        self.color.fill(None);
+    }
+
+    /// Whether anything was drawn into a pixel
+    pub fn is_lit(&self, x: usize, y: usize) -> bool {
+        self.depth[x + y * self.width].is_finite()
+    }
+
+    /// Encodes pixels into chars of `cells`, which has to be `RenderMode::scale` times smaller
+    ///
+    /// # Parameters
+    /// - `mode`: how pixels are grouped into cells, `Ascii` keeps the char of the nearest pixel
+    /// - `cells`: buffer receiving chars, colors and depth of each cell
+    pub fn encode(&self, mode: RenderMode, cells: &mut AsciiBuffer) {
+        let (sx, sy) = mode.scale();
+        let (columns, rows) = (cells.chunk_width as usize, cells.height());
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let pixels: Vec<(usize, usize)> = (0..sy)
+                    .flat_map(|dy| (0..sx).map(move |dx| (column * sx + dx, row * sy + dy)))
+                    .filter(|&(x, y)| x < self.width && y < self.height)
+                    .collect();
+
+                let depth = pixels.iter().map(|&(x, y)| self.depth[x + y * self.width]).fold(f64::INFINITY, f64::min);
+                let (value, foreground, background) = match mode {
+                    RenderMode::Ascii => self.ascii(column, row),
+                    RenderMode::HalfBlock => self.half_block(column, row),
+                    RenderMode::Braille => self.braille(&pixels),
+                };
+
+                let idx = column + row * columns;
+                cells.buffer[idx] = value;
+                cells.depth[idx] = depth;
+                cells.foreground[idx] = foreground;
+                cells.background[idx] = background;
+            }
+        }
+    }
+
+    /// Color of a lit pixel, gray by its brightness when the surface has no color
+    fn shade(&self, x: usize, y: usize) -> Option<Color> {
+        if x >= self.width || y >= self.height || !self.is_lit(x, y) {
+            return None;
+        }
+
+        let idx = x + y * self.width;
+        self.color[idx].or_else(|| Some(Color::new(255, 255, 255).scale(self.intensity[idx])))
+    }
+
+    fn ascii(&self, x: usize, y: usize) -> (char, Option<Color>, Option<Color>) {
+        match x < self.width && y < self.height {
+            true => (self.value[x + y * self.width], self.color[x + y * self.width], None),
+            false => (' ', None, None),
+        }
+    }
+
+    fn half_block(&self, column: usize, row: usize) -> (char, Option<Color>, Option<Color>) {
+        match (self.shade(column, row * 2), self.shade(column, row * 2 + 1)) {
+            (None, None) => (' ', None, None),
+            (Some(top), None) => ('▀', Some(top), None),
+            (None, Some(bottom)) => ('▄', Some(bottom), None),
+            (Some(top), Some(bottom)) if top == bottom => ('█', Some(top), None),
+            (Some(top), Some(bottom)) => ('▀', Some(top), Some(bottom)),
+        }
+    }
+
+    fn braille(&self, pixels: &[(usize, usize)]) -> (char, Option<Color>, Option<Color>) {
+        let mut bits = 0;
+        let mut lit: Vec<Color> = vec![];
+
+        for &(x, y) in pixels {
+            let Some(color) = self.shade(x, y) else { continue };
+            let threshold = (BAYER_4X4[y % 4][x % 4] + 0.5) / 16.0;
+
+            if self.intensity[x + y * self.width] >= threshold {
+                bits |= BRAILLE_DOTS[y % 4][x % 2];
+                lit.push(color);
+            }
+        }
+
+        if lit.is_empty() {
+            return (' ', None, None);
+        }
+
+        let average = |f: fn(&Color) -> u8| (lit.iter().map(|c| f(c) as usize).sum::<usize>() / lit.len()) as u8;
+        let color = Color::new(average(|c| c.r), average(|c| c.g), average(|c| c.b));
+
+        (char::from_u32(0x2800 + bits).unwrap(), Some(color), None)
+    }
+}
+
+impl RenderTarget for PixelBuffer {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn write(&mut self, x: usize, y: usize, depth: f64, value: char, intensity: f64, color: Option<Color>) -> bool {
+        let idx = x + y * self.width;
+        if depth >= self.depth[idx] {
+            return false;
+        }
+
+        self.depth[idx] = depth;
+        self.value[idx] = value;
+        self.intensity[idx] = intensity.clamp(0.0, 1.0);
+        self.color[idx] = color;
+
+        true
+    }
+}
@@ -0,0 +1,152 @@
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Print, ResetColor, SetBackgroundColor, SetForegroundColor},
+    terminal::{Clear, ClearType},
+};
+
+use crate::prelude::*;
+
+/// Writes frames to a terminal, emitting only cells which changed since the last frame.
+/// Adjacent changed cells of a row are written after a single cursor move.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let mut presenter = Presenter::new(ColorMode::Monochrome);
+/// let mut frame = AsciiBuffer::new(4, vec![' '; 8]);
+/// let mut out = Vec::new();
+///
+/// assert_eq!(presenter.present(&frame, &mut out).unwrap(), 8);
+///
+/// frame[(1, 0)] = '#';
+/// frame[(2, 0)] = '#';
+///
+/// assert_eq!(presenter.present(&frame, &mut out).unwrap(), 2);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Presenter {
+    /// Frame as it was last written
+    last: Option<AsciiBuffer>,
+    /// Colors supported by the terminal
+    color_mode: ColorMode,
+    /// Whether every frame is written completely
+    full_redraw: bool,
+    /// Whether the screen is cleared before the next frame, set by `invalidate`
+    clear: bool,
+}
+
+impl Presenter {
+    pub fn new(color_mode: ColorMode) -> Self {
+        Self { last: None, color_mode, full_redraw: false, clear: false }
+    }
+
+    /// Writes every cell of every frame, e.g. when other programs may draw into the terminal
+    ///
+    /// # Returns
+    /// itself for continuous building chain
+    pub fn with_full_redraw(mut self, full_redraw: bool) -> Self {
+        self.full_redraw = full_redraw;
+
+        self
+    }
+
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    pub fn set_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+        self.invalidate();
+    }
+
+    /// Forgets the last frame so the screen is cleared and the next frame is written completely,
+    /// e.g. after the terminal was resized
+    pub fn invalidate(&mut self) {
+        self.last = None;
+        self.clear = true;
+    }
+
+    /// Writes cells of a frame which differ from the previous one.
+    /// The screen is cleared first when the frame has other dimensions than the previous one,
+    /// otherwise cells outside of the smaller frame would keep old chars.
+    ///
+    /// # Parameters
+    /// - `frame`: buffer to be shown
+    /// - `out`: terminal receiving the commands
+    ///
+    /// # Returns
+    /// number of cells written
+    pub fn present(&mut self, frame: &AsciiBuffer, out: &mut impl Write) -> io::Result<usize> {
+        let previous = self.last.as_ref().filter(|last| {
+            !self.full_redraw && last.chunk_width == frame.chunk_width && last.len() == frame.len()
+        });
+        let changed = |i: usize| {
+            previous.is_none_or(|p| {
+                p.buffer[i] != frame.buffer[i] || p.foreground[i] != frame.foreground[i] || p.background[i] != frame.background[i]
+            })
+        };
+
+        let resized = self.last.as_ref().is_some_and(|last| last.chunk_width != frame.chunk_width || last.len() != frame.len());
+
+        let width = frame.chunk_width as usize;
+        let mut written = 0;
+        let mut current = (None, None);
+        queue!(out, ResetColor)?;
+
+        if resized || self.clear {
+            queue!(out, Clear(ClearType::All))?;
+            self.clear = false;
+        }
+
+        for row in 0..frame.height() {
+            let mut column = 0;
+
+            while column < width {
+                if !changed(column + row * width) {
+                    column += 1;
+                    continue;
+                }
+
+                queue!(out, MoveTo(column as u16, row as u16))?;
+
+                while column < width && changed(column + row * width) {
+                    let i = column + row * width;
+                    let colors = (
+                        frame.foreground[i].and_then(|c| self.color_mode.convert(c)),
+                        frame.background[i].and_then(|c| self.color_mode.convert(c)),
+                    );
+
+                    if colors != current {
+                        queue!(out, ResetColor)?;
+                        if let Some(fg) = colors.0 {
+                            queue!(out, SetForegroundColor(fg))?;
+                        }
+                        if let Some(bg) = colors.1 {
+                            queue!(out, SetBackgroundColor(bg))?;
+                        }
+                        current = colors;
+                    }
+
+                    queue!(out, Print(frame.buffer[i]))?;
+                    written += 1;
+                    column += 1;
+                }
+            }
+        }
+
+        queue!(out, ResetColor)?;
+        out.flush()?;
+
+        match &mut self.last {
+            Some(last) if last.len() == frame.len() => last.clone_from(frame),
+            _ => self.last = Some(frame.clone()),
+        }
+
+        Ok(written)
+    }
+}
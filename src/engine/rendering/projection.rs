@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+/// enum representing how a camera maps 3D space onto the screen
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Projection {
+    /// Distant objects appear smaller, field of view of the camera is used
+    #[default]
+    Perspective,
+    /// Objects keep their size regardless of the distance, useful for technical previews
+    Orthographic {
+        /// Height of the visible volume in world units, smaller value zooms in
+        view_height: f64,
+    },
+}
+
+impl Projection {
+    /// Panics when the projection would map points to infinite or invalid coordinates
+    pub(crate) fn validate(&self) {
+        if let Projection::Orthographic { view_height } = self {
+            assert!(*view_height > 0.0, "orthographic view height must be greater than zero, got {view_height}");
+        }
+    }
+
+    pub fn is_perspective(&self) -> bool {
+        matches!(self, Projection::Perspective)
+    }
+
+    /// Direction in which the camera sees a point in camera space
+    ///
+    /// # Parameters
+    /// -`point`: point in camera space
+    ///
+    /// # Returns
+    /// vector pointing from the camera towards the point, not normalized
+    pub fn view_direction(&self, point: Vec3) -> Vec3 {
+        match self {
+            Projection::Perspective => point,
+            Projection::Orthographic { .. } => Vec3::new(0.0, 0.0, 1.0),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+/// Value returned by the update callback of `Renderer::run` deciding whether the loop goes on
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoopControl {
+    #[default]
+    Continue,
+    Stop,
+}
+
+/// Controls a running render loop from anywhere, e.g. another thread or an input handler.
+/// Clones control the same loop.
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let handle = RenderHandle::new();
+/// let remote = handle.clone();
+///
+/// remote.pause();
+/// assert!(handle.is_paused());
+///
+/// remote.stop();
+/// assert!(handle.is_stopped());
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct RenderHandle {
+    stopped: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+}
+
+impl RenderHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ends the loop after the current frame
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// Stops updating and drawing frames until resumed
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    pub fn toggle_pause(&self) {
+        self.paused.fetch_xor(true, Ordering::SeqCst);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Allows the loop to run again after it was stopped
+    pub(crate) fn reset(&self) {
+        self.stopped.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Timing of frames drawn by a render loop, time spent paused is not counted
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrameStats {
+    /// Number of frames drawn
+    pub frames: u64,
+    /// Time between the last two frames, passed to the update callback
+    pub delta: Duration,
+    /// Time spent updating, drawing and presenting the last frame
+    pub frame_time: Duration,
+    /// Average of `frame_time` over all frames
+    pub average_frame_time: Duration,
+    /// Longest `frame_time` of all frames
+    pub max_frame_time: Duration,
+    /// Total running time of the loop
+    pub elapsed: Duration,
+}
+
+impl FrameStats {
+    /// Frames drawn per second on average
+    pub fn fps(&self) -> f64 {
+        match self.elapsed.is_zero() {
+            true => 0.0,
+            false => self.frames as f64 / self.elapsed.as_secs_f64(),
+        }
+    }
+
+    /// Records a finished frame
+    pub(crate) fn record(&mut self, delta: Duration, frame_time: Duration) {
+        self.frames += 1;
+        self.delta = delta;
+        self.frame_time = frame_time;
+        self.max_frame_time = self.max_frame_time.max(frame_time);
+        self.elapsed += delta;
+        let average = self.average_frame_time.as_secs_f64();
+        self.average_frame_time = Duration::from_secs_f64(average + (frame_time.as_secs_f64() - average) / self.frames as f64);
+    }
+}
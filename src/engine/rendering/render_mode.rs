@@ -0,0 +1,23 @@
+/// enum representing how the scene is turned into terminal chars
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// One char per cell picked from the brightness ramp of the material
+    #[default]
+    Ascii,
+    /// Two pixels per cell stacked vertically, drawn by `▀` and `▄` with foreground and background colors
+    HalfBlock,
+    /// Eight dots per cell in two columns and four rows, drawn by braille patterns
+    Braille,
+}
+
+impl RenderMode {
+    /// Number of pixels per cell horizontally and vertically
+    pub fn scale(&self) -> (usize, usize) {
+        match self {
+            RenderMode::Ascii => (1, 1),
+            RenderMode::HalfBlock => (1, 2),
+            RenderMode::Braille => (2, 4),
+        }
+    }
+}
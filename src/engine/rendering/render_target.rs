@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+/// Grid the Transferer writes rasterized fragments into
+#[allow(dead_code)]
+pub trait RenderTarget {
+    /// Number of columns
+    fn width(&self) -> usize;
+    /// Number of rows
+    fn height(&self) -> usize;
+    /// Writes a shaded fragment only if it is closer to the camera than the one already stored
+    ///
+    /// # Parameters
+    /// - `x`: column of the fragment
+    /// - `y`: row of the fragment
+    /// - `depth`: distance of the fragment from the camera
+    /// - `value`: char picked by the material of the surface
+    /// - `intensity`: brightness of the surface
+    /// - `color`: lit color of the surface
+    ///
+    /// # Returns
+    /// true if the fragment passed the depth test and was written
+    fn write(&mut self, x: usize, y: usize, depth: f64, value: char, intensity: f64, color: Option<Color>) -> bool;
+}
+
+impl RenderTarget for AsciiBuffer {
+    fn width(&self) -> usize {
+        self.chunk_width as usize
+    }
+
+    fn height(&self) -> usize {
+        AsciiBuffer::height(self)
+    }
+
+    fn write(&mut self, x: usize, y: usize, depth: f64, value: char, _intensity: f64, color: Option<Color>) -> bool {
+        self.update_colored_at_depth(x, y, depth, value, color, None)
+    }
+}
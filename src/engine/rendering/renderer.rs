@@ -1,6 +1,6 @@
-use std::{io::{Write, stdout}};
-use crossterm::queue;
-use crate::{Camera, Mesh, Transferer, Vec3, engine::{graphics::Size, rendering::{AsciiBuffer, RenderQuality}}};
+use std::time::{Duration, Instant};
+
+use crate::{Backend, Camera, FrameStats, LoopControl, RenderHandle, ColorMode, CullMode, IndexedMesh, Light, PixelBuffer, RenderMode, TerminalBackend, Scene, ShadingMode, TransferOptions, Vec3, WindingOrder, engine::{graphics::Size, rendering::{AsciiBuffer, RenderQuality}}};
 
 #[allow(dead_code)]
 pub struct Renderer {
@@ -10,48 +10,164 @@ pub struct Renderer {
     fps: i16,
     quality: RenderQuality,
     on_failed: Box<dyn Fn(&'static str)>,
-    light_direction: Vec3,
-    meshes: Vec<Mesh>
+    options: TransferOptions,
+    backend: Box<dyn Backend>,
+    render_mode: RenderMode,
+    pixel_buffer: PixelBuffer,
+    scene: Scene,
+    handle: RenderHandle,
+    stats: FrameStats
 }
 
 #[allow(dead_code)]
 impl Renderer {
     #[allow(clippy::new_ret_no_self)]
     pub fn new() -> RendererBuilder {
-        RendererBuilder { buffer_size: None, fps: 60, quality: None, light_direction: None }
+        RendererBuilder { buffer_size: None, fps: 60, quality: None, light_direction: None, lights: vec![], options: TransferOptions::default(), color_mode: None, full_redraw: false, render_mode: RenderMode::default(), backend: None }
     }
 
     pub fn on_error<T: Fn(&'static str) + 'static>(&mut self, f: T) {
         self.on_failed = Box::new(f);
     }
 
-    fn render(&self) {
-        self.front_buffer.print();
+    fn render(&mut self) {
+        if self.backend.present(&self.front_buffer).is_err() {
+            (self.on_failed)("Failed to present a frame");
+        }
     }
 
+    /// Writes every cell of the next frame, e.g. after something else drew into the terminal
+    pub fn request_full_redraw(&mut self) {
+        self.backend.invalidate();
+    }
+
+    /// Draws the scene until stopped through `handle`, see `Renderer::run`
     pub fn start(&mut self, camera: &Camera) {
-        let mut stdout = stdout();
-        queue!(stdout, crossterm::cursor::Hide).unwrap();
+        self.run(&mut camera.clone(), |_, _, _| LoopControl::Continue);
+    }
 
-        let frame_time = std::time::Duration::from_millis(1000 / self.fps as u64);
+    /// Draws frames until the update callback returns `LoopControl::Stop`, the loop is stopped through `handle`
+    /// or the backend reports a request to quit, e.g. Ctrl+C in the terminal.
+    /// The default terminal backend holds a `TerminalSession` while the loop runs.
+    /// Frames are spaced to keep the configured fps, a fps of zero or less draws frames as fast as possible.
+    /// A stop issued before the call ends the loop before the first frame, the next call runs again.
+    /// When the backend fails to initialize, `on_error` is called and no frame is drawn.
+    ///
+    /// # Parameters
+    /// - `camera`: camera looking at the scene, it can be moved by the callback
+    /// - `update`: called before every frame with the scene, the camera and the time since the previous frame
+    ///
+    /// # Returns
+    /// timing of all drawn frames
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use rustender::prelude::*;
+    ///
+    /// let mut renderer = Renderer::new().fps(30).build();
+    /// renderer.add_mesh(MESHES::torus(2.0, 0.6, 32, 16));
+    /// let mut camera = Camera::new().location([0.0, 0.0, -6.0].into()).build();
+    ///
+    /// let stats = renderer.run(&mut camera, |scene, _, delta| {
+    ///     scene.meshes[0].rotation.y += delta.as_secs_f64();
+    ///
+    ///     LoopControl::Continue
+    /// });
+    ///
+    /// println!("{:.1} fps", stats.fps());
+    /// ```
+    pub fn run<F>(&mut self, camera: &mut Camera, mut update: F) -> FrameStats
+    where
+        F: FnMut(&mut Scene, &mut Camera, Duration) -> LoopControl,
+    {
+        self.stats = FrameStats::default();
 
-        loop {
-            let start = std::time::Instant::now();
-            self.calculate_next_scene(camera);
+        // Drawing to an output which is not set up, e.g. a terminal owned by another session, would garble it
+        if self.backend.init().is_err() {
+            (self.on_failed)("Failed to initialize the output");
+            self.handle.reset();
 
-            self.render();
+            return self.stats;
+        }
+
+        let frame_time = match self.fps > 0 {
+            true => Duration::from_secs_f64(1.0 / self.fps as f64),
+            false => Duration::ZERO,
+        };
 
-            stdout.flush().unwrap();
+        let mut previous = Instant::now();
+        let mut deadline = previous;
 
-            let elapsed = start.elapsed();
-            if elapsed < frame_time {
-                std::thread::sleep(frame_time - elapsed);
+        while !self.handle.is_stopped() {
+            if self.backend.quit_requested() {
+                break;
             }
+
+            if self.handle.is_paused() {
+                std::thread::sleep(frame_time.clamp(Duration::from_millis(1), Duration::from_millis(50)));
+                // Time spent paused does not count into the delta of the next frame
+                previous = Instant::now();
+                deadline = previous;
+                continue;
+            }
+
+            let start = Instant::now();
+            let delta = start - previous;
+            previous = start;
+
+            if update(&mut self.scene, camera, delta) == LoopControl::Stop {
+                break;
+            }
+
+            self.calculate_next_scene(camera);
+            self.render();
+            self.stats.record(delta, start.elapsed());
+
+            // Sleeping until a deadline keeps the pace even when some frames take longer,
+            // a loop falling behind continues from now instead of rushing to catch up
+            let now = Instant::now();
+            deadline += frame_time;
+            if deadline > now {
+                std::thread::sleep(deadline - now);
+            } else {
+                deadline = now;
+            }
+        }
+
+        if self.backend.restore().is_err() {
+            (self.on_failed)("Failed to restore the output");
         }
+        // Cleared once the loop ended so a stop issued before `run` is not lost
+        self.handle.reset();
+
+        self.stats
+    }
+
+    /// Handle stopping, pausing and resuming the render loop
+    pub fn handle(&self) -> RenderHandle {
+        self.handle.clone()
+    }
+
+    /// Timing of frames drawn by the current or the last render loop
+    pub fn stats(&self) -> &FrameStats {
+        &self.stats
+    }
+
+    /// Adds a Mesh to the scene, see `Scene::add_mesh`
+    pub fn add_mesh(&mut self, mesh: impl Into<IndexedMesh>) {
+        self.scene.add_mesh(mesh);
+    }
+
+    pub fn add_light(&mut self, light: Light) {
+        self.scene.add_light(light);
+    }
+
+    pub fn scene(&self) -> &Scene {
+        &self.scene
     }
 
-    pub fn add_mesh(&mut self, mesh: Mesh) {
-        self.meshes.push(mesh);
+    pub fn scene_mut(&mut self) -> &mut Scene {
+        &mut self.scene
     }
 
     fn sync_buffers(&mut self) {
@@ -59,11 +175,31 @@ impl Renderer {
     }
 
     fn calculate_next_scene(&mut self, camera: &Camera) {
-        let mut transferer  = Transferer(&mut self.back_buffer, &self.meshes);
-
-        transferer.start_transfering(camera, self.light_direction);
+        self.back_buffer.clear();
+        self.scene.draw(camera, &self.options, self.render_mode, &mut self.pixel_buffer, &mut self.back_buffer);
         self.sync_buffers();
     }
+
+    /// Renders a single frame without showing it on the backend, e.g. for previews or snapshot tests
+    ///
+    /// # Parameters
+    /// - `camera`: camera looking at the scene
+    ///
+    /// # Returns
+    /// the finished frame
+    pub fn render_frame(&mut self, camera: &Camera) -> &AsciiBuffer {
+        self.calculate_next_scene(camera);
+
+        &self.front_buffer
+    }
+
+    /// Renders a single frame without showing it on the backend
+    ///
+    /// # Returns
+    /// chars of the frame with rows separated by new lines
+    pub fn render_to_string(&mut self, camera: &Camera) -> String {
+        self.render_frame(camera).to_string()
+    }
 }
 
 #[allow(dead_code)]
@@ -71,29 +207,37 @@ pub struct RendererBuilder {
     buffer_size: Option<Size>,
     fps: i16,
     quality: Option<RenderQuality>,
-    light_direction: Option<Vec3>
+    light_direction: Option<Vec3>,
+    lights: Vec<Light>,
+    options: TransferOptions,
+    color_mode: Option<ColorMode>,
+    full_redraw: bool,
+    render_mode: RenderMode,
+    backend: Option<Box<dyn Backend>>
 }
 
 impl RendererBuilder {
     pub fn build(self) -> Renderer {
+        let backend = match self.backend {
+            Some(backend) => backend,
+            None => Box::new(
+                TerminalBackend::new(self.color_mode.unwrap_or_else(ColorMode::detect)).with_full_redraw(self.full_redraw)
+            )
+        };
+
         let buffer_size = match self.buffer_size.clone() {
             Some(size) => size,
             None => {
-                let (cols, rows) = crossterm::terminal::size().unwrap_or((100, 50));
+                let (cols, rows) = backend.size().unwrap_or((100, 50));
                 Size::new(cols as f32, rows as f32)
             }
         };
+        let cells = (buffer_size.width * buffer_size.height) as usize;
 
         Renderer { 
             buffer_size: buffer_size.clone(),
-            front_buffer: AsciiBuffer::new(buffer_size.clone().width as u32, vec![' '; match &self.buffer_size {
-                Some(size) => (size.height * size.width) as usize,
-                None => 100 * 50
-            }]), 
-            back_buffer: AsciiBuffer::new(buffer_size.clone().width as u32, vec![' '; match &self.buffer_size {
-                Some(size) => (size.height * size.width) as usize,
-                None => 100 * 50
-            }]), 
+            front_buffer: AsciiBuffer::new(buffer_size.width as u32, vec![' '; cells]), 
+            back_buffer: AsciiBuffer::new(buffer_size.width as u32, vec![' '; cells]), 
             fps: self.fps, 
             quality: match &self.quality {
                 Some(q) => q.clone(),
@@ -102,11 +246,20 @@ impl RendererBuilder {
             on_failed: Box::new(|s| {
                 println!("{s}");
             }),
-            light_direction: match &self.light_direction {
-                Some(l) => *l,
-                None => Vec3::new(0.0, 0.0, -1.0).normalize()
+            options: self.options,
+            backend,
+            render_mode: self.render_mode,
+            pixel_buffer: PixelBuffer::new(0, 0),
+            scene: Scene {
+                meshes: vec![],
+                lights: match (self.light_direction, self.lights.is_empty()) {
+                    (Some(direction), _) => [vec![Light::directional(direction, 1.0)], self.lights].concat(),
+                    (None, true) => vec![Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0)],
+                    (None, false) => self.lights
+                }
             },
-            meshes: vec![]
+            handle: RenderHandle::new(),
+            stats: FrameStats::default()
         }
     }
 
@@ -155,9 +308,65 @@ impl RendererBuilder {
         self
     }
 
+    /// Sets the direction of the main light, a directional light of full intensity.
+    /// Calling it again replaces the direction, use `light` to add more lights.
     pub fn light_direction(mut self, direction: Vec3) -> Self {
         self.light_direction = Some(direction);
 
         self
     }
+
+    /// Adds a light next to the main light.
+    /// Without `light_direction` and any added light the scene is lit by a light behind the camera.
+    pub fn light(mut self, light: Light) -> Self {
+        self.lights.push(light);
+
+        self
+    }
+
+    pub fn cull_mode(mut self, cull_mode: CullMode) -> Self {
+        self.options.cull_mode = cull_mode;
+
+        self
+    }
+
+    pub fn winding_order(mut self, winding_order: WindingOrder) -> Self {
+        self.options.winding_order = winding_order;
+
+        self
+    }
+
+    /// Shows frames on another output than the terminal, see `Backend`
+    pub fn backend(mut self, backend: impl Backend + 'static) -> Self {
+        self.backend = Some(Box::new(backend));
+
+        self
+    }
+
+    /// Overrides colors detected from the terminal environment, applies to the default terminal backend
+    pub fn color_mode(mut self, color_mode: ColorMode) -> Self {
+        self.color_mode = Some(color_mode);
+
+        self
+    }
+
+    /// Writes every cell of every frame instead of only the changed ones, applies to the default terminal backend
+    pub fn full_redraw(mut self, full_redraw: bool) -> Self {
+        self.full_redraw = full_redraw;
+
+        self
+    }
+
+    /// Selects chars drawing the scene, `HalfBlock` and `Braille` give more detail on the same terminal
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.render_mode = render_mode;
+
+        self
+    }
+
+    pub fn shading_mode(mut self, shading_mode: ShadingMode) -> Self {
+        self.options.shading_mode = shading_mode;
+
+        self
+    }
 }
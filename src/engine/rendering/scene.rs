@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+/// Everything a Renderer draws: meshes and the lights shining on them
+///
+/// # Examples
+/// ```
+/// use rustender::prelude::*;
+///
+/// let mut scene = Scene::new();
+/// scene.add_mesh(MESHES::cube(2.0));
+/// scene.add_light(Light::ambient(0.1));
+/// scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 0.9));
+/// ```
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scene {
+    pub meshes: Vec<IndexedMesh>,
+    pub lights: Vec<Light>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a Mesh to the scene, a `Mesh` is converted into an `IndexedMesh` once here.
+    /// Meshes without normals get smooth normals computed for Gouraud and Phong shading.
+    pub fn add_mesh(&mut self, mesh: impl Into<IndexedMesh>) {
+        let mut mesh = mesh.into();
+
+        if mesh.normals.is_none() {
+            mesh.compute_normals(NormalWeighting::default());
+        }
+
+        self.meshes.push(mesh);
+    }
+
+    pub fn add_light(&mut self, light: Light) {
+        self.lights.push(light);
+    }
+
+    /// Renders a single frame of the scene with default settings
+    ///
+    /// # Parameters
+    /// - `camera`: camera looking at the scene, its width and height is the size of the frame
+    ///
+    /// # Examples
+    /// ```
+    /// use rustender::prelude::*;
+    ///
+    /// let mut scene = Scene::new();
+    /// scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0));
+    /// scene.add_mesh(Mesh::new()
+    ///     .set_triangles(MESHES::cube(2.0).triangles)
+    ///     .set_position([0.0, 0.0, 5.0].into())
+    ///     .build());
+    ///
+    /// let frame = scene.render(&Camera::new().width(40).height(20).build());
+    ///
+    /// assert_eq!(frame[(20, 10)], '@');
+    /// println!("{frame}");
+    /// ```
+    pub fn render(&self, camera: &Camera) -> AsciiBuffer {
+        self.render_with(camera, &TransferOptions::default(), RenderMode::default())
+    }
+
+    /// Renders a single frame of the scene
+    ///
+    /// # Parameters
+    /// - `camera`: camera looking at the scene, its width and height is the size of the frame
+    /// - `options`: settings of culling and shading
+    /// - `mode`: chars drawing the scene
+    pub fn render_with(&self, camera: &Camera, options: &TransferOptions, mode: RenderMode) -> AsciiBuffer {
+        let mut frame = AsciiBuffer::new(camera.width as u32, vec![' '; camera.width * camera.height]);
+        self.draw(camera, options, mode, &mut PixelBuffer::new(0, 0), &mut frame);
+
+        frame
+    }
+
+    /// Draws the scene into a cleared frame, `pixels` is reused between frames by the high resolution modes
+    pub(crate) fn draw(&self, camera: &Camera, options: &TransferOptions, mode: RenderMode, pixels: &mut PixelBuffer, frame: &mut AsciiBuffer) {
+        match mode {
+            RenderMode::Ascii => {
+                Transferer(frame, self).start_transfering(camera, options);
+            }
+            mode => {
+                // Rasterize into pixels smaller than cells and encode them into chars afterwards
+                let (columns, rows) = mode.scale();
+                let camera = camera.scaled(columns, rows);
+
+                if pixels.width != camera.width || pixels.height != camera.height {
+                    pixels.resize(camera.width, camera.height);
+                } else {
+                    pixels.clear();
+                }
+
+                Transferer(&mut *pixels, self).start_transfering(&camera, options);
+                pixels.encode(mode, frame);
+            }
+        }
+    }
+}
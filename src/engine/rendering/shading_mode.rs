@@ -0,0 +1,12 @@
+/// enum representing how lighting is spread across the surface of a triangle
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShadingMode {
+    /// One intensity for the whole face, calculated from its geometric normal
+    #[default]
+    Flat,
+    /// Intensity is calculated at the corners and blended across the face
+    Gouraud,
+    /// Corner normals are blended across the face and lit for every fragment
+    Phong,
+}
@@ -0,0 +1,104 @@
+use std::{
+    io::{self, stdout},
+    sync::{
+        Once,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crossterm::{
+    cursor,
+    event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+/// Whether a session currently owns the terminal, read by the panic hook
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PANIC_HOOK: Once = Once::new();
+
+/// Guard taking over the terminal of the process: switches to the alternate screen,
+/// enables raw mode and hides the cursor. Everything is restored when the guard is dropped
+/// or when the program panics while it is alive.
+///
+/// Raw mode turns Ctrl+C into a key event instead of a signal, see `TerminalSession::is_interrupt`.
+///
+/// # Examples
+/// ```no_run
+/// use rustender::prelude::*;
+///
+/// let session = TerminalSession::begin().expect("not a terminal");
+/// // draw frames...
+/// drop(session);
+/// ```
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct TerminalSession {
+    _private: (),
+}
+
+impl TerminalSession {
+    /// Takes over the terminal
+    ///
+    /// # Returns
+    /// the guard, or an error when the standard output is not a terminal or another session is active
+    pub fn begin() -> io::Result<Self> {
+        PANIC_HOOK.call_once(|| {
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                // Restore first, so the panic message is readable on the normal screen
+                if ACTIVE.swap(false, Ordering::SeqCst) {
+                    let _ = Self::restore();
+                }
+                previous(info);
+            }));
+        });
+
+        // Only one session may own the terminal, the first one to drop would restore it under the other
+        if ACTIVE.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, "a terminal session is already active"));
+        }
+
+        if let Err(error) = terminal::enable_raw_mode() {
+            ACTIVE.store(false, Ordering::SeqCst);
+            return Err(error);
+        }
+
+        if let Err(error) = execute!(stdout(), EnterAlternateScreen, cursor::Hide) {
+            ACTIVE.store(false, Ordering::SeqCst);
+            let _ = Self::restore();
+            return Err(error);
+        }
+
+        Ok(Self { _private: () })
+    }
+
+    /// Whether a session currently owns the terminal
+    pub fn is_active() -> bool {
+        ACTIVE.load(Ordering::SeqCst)
+    }
+
+    /// Checks whether an input event asks to quit, which is Ctrl+C or Ctrl+D in raw mode
+    pub fn is_interrupt(event: &Event) -> bool {
+        matches!(
+            event,
+            Event::Key(KeyEvent { code: KeyCode::Char('c' | 'd'), modifiers, kind: KeyEventKind::Press | KeyEventKind::Repeat, .. })
+                if modifiers.contains(KeyModifiers::CONTROL)
+        )
+    }
+
+    fn restore() -> io::Result<()> {
+        let screen = execute!(stdout(), cursor::Show, LeaveAlternateScreen);
+        let raw = terminal::disable_raw_mode();
+
+        screen.and(raw)
+    }
+}
+
+impl Drop for TerminalSession {
+    fn drop(&mut self) {
+        if ACTIVE.swap(false, Ordering::SeqCst) {
+            let _ = Self::restore();
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+/// Settings of the transfer pipeline turning meshes into chars
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransferOptions {
+    /// Which faces are skipped
+    pub cull_mode: CullMode,
+    /// Winding order of front faces
+    pub winding_order: WindingOrder,
+    /// How lighting is spread across faces, meshes without normals are always shaded flat
+    pub shading_mode: ShadingMode,
+}
@@ -1,40 +1,108 @@
 use crate::prelude::*;
 
+/// Draws a Scene into a render target, an AsciiBuffer by default
 #[allow(dead_code)]
-pub struct Transferer<'a>(pub &'a mut AsciiBuffer, pub &'a Vec<Mesh>);
-
-impl<'a> Transferer<'a> {
-    pub fn start_transfering(&mut self, camera: &Camera, light_dir: Vec3) {
-        self.1.iter().for_each(|m| {
-            m.triangles.iter().for_each(|t| {
-                let normal = t.normal();
-                let intensity = normal.dot(light_dir.normalize()).max(0.0);
-                let ascii = ascii_from_intensity(intensity);
-
-                // Project vertices to 2D FIRST
-                let projected_vertices: [Vec2; 3] = [
-                    t.vertices[0].get_projected_2d(camera.get_focal_length()),
-                    t.vertices[1].get_projected_2d(camera.get_focal_length()),
-                    t.vertices[2].get_projected_2d(camera.get_focal_length()),
-                ];
-
-                // Then rasterize in 2D screen space
-                let rasterized_pos = Triangle::rasterize_2d_triangle(projected_vertices);
-
-                rasterized_pos.iter().for_each(|v| {
-                    // Center on screen
-                    let x = (v.x + (camera.width as f64 / 2.0)) as isize;
-                    let y = (v.y + (camera.height as f64 / 2.0)) as isize;
-
-                    if x >= 0
-                        && y >= 0
-                        && x < self.0.chunk_width as isize
-                        && y < (self.0.buffer.len() as isize / self.0.chunk_width as isize)
-                    {
-                        self.0.update_at(x as usize, y as usize, ascii);
+pub struct Transferer<'a, T: RenderTarget = AsciiBuffer>(pub &'a mut T, pub &'a Scene);
+
+impl<'a, T: RenderTarget> Transferer<'a, T> {
+    pub fn start_transfering(&mut self, camera: &Camera, options: &TransferOptions) {
+        // View transform: world space -> camera space
+        let view = camera.view_matrix();
+        let frustum = camera.frustum();
+
+        // Lighting is calculated in camera space where the viewer sits at the origin
+        let lights: Vec<Light> = self.1.lights.iter().map(|l| l.transform(&view)).collect();
+
+        self.1.meshes.iter().for_each(|m| {
+            let shade = |point: Vec3, normal: Vec3| {
+                let to_viewer = -camera.projection().view_direction(point).try_normalize().unwrap_or(Vec3::new(0.0, 0.0, 1.0));
+                let illumination = lights.iter().fold(Illumination::default(), |sum, l| {
+                    sum + l.illuminate(point, normal, to_viewer, m.material.shininess)
+                });
+
+                m.material.intensity(&illumination)
+            };
+            // Surface color is darkened by the light, a material color overrides vertex colors
+            let paint = |t: &Triangle, weights: [f64; 3], intensity: f64| {
+                let color = m.material.color.or_else(|| t.interpolate_color(weights));
+
+                (m.material.ascii(intensity), intensity, color.map(|c| c.scale(intensity)))
+            };
+
+            // Model transform: object space -> world space, followed by the view transform
+            let world_view = view * m.world_matrix();
+            let rotation = Matrix::<f64, 4, 4>::from(rotation_matrix_3d(&m.rotation));
+
+            // Shared vertices are transformed only once per frame
+            let view_vertices: Vec<Vec3> = m.vertices.iter().map(|v| world_view * *v).collect();
+            let view_normals: Option<Vec<Vec3>> = m.normals.as_ref()
+                .filter(|_| options.shading_mode != ShadingMode::Flat)
+                .map(|normals| normals.iter().map(|n| Triangle::transform_normal(&(view * rotation), *n)).collect());
+
+            m.indices.iter().for_each(|&[a, b, c]| {
+                let mut t_view = Triangle::new([view_vertices[a], view_vertices[b], view_vertices[c]]);
+
+                if options.cull_mode.culls(&t_view, options.winding_order, camera) {
+                    return;
+                }
+
+                let face_normal = t_view.normal();
+                let face_intensity = shade(t_view.interpolate_vertex([1.0 / 3.0; 3]), face_normal);
+                if let Some(normals) = &view_normals {
+                    t_view = t_view.with_normals([normals[a], normals[b], normals[c]]);
+                }
+                if let Some(colors) = m.colors.as_ref().filter(|_| m.material.color.is_none()) {
+                    t_view = t_view.with_colors([colors[a], colors[b], colors[c]]);
+                }
+
+                // Clip against the frustum so nothing behind the camera gets projected
+                frustum.clip_triangle(&t_view).iter().for_each(|t| {
+                    match (options.shading_mode, t.normals) {
+                        (ShadingMode::Gouraud, Some(normals)) => {
+                            let corners = [0, 1, 2].map(|i| shade(t.vertices[i], normals[i]));
+                            self.rasterize(camera, t, |w| {
+                                paint(t, w, corners[0] * w[0] + corners[1] * w[1] + corners[2] * w[2])
+                            });
+                        }
+                        (ShadingMode::Phong, Some(_)) => {
+                            self.rasterize(camera, t, |w| {
+                                paint(t, w, shade(t.interpolate_vertex(w), t.interpolate_normal(w).unwrap()))
+                            });
+                        }
+                        _ => {
+                            self.rasterize(camera, t, |w| paint(t, w, face_intensity));
+                        }
                     }
-                })
+                });
             })
         });
     }
+
+    /// Rasterizes a camera space triangle, `shade` picks a char, brightness and color from the barycentric weights of each fragment
+    fn rasterize(&mut self, camera: &Camera, t: &Triangle, shade: impl Fn([f64; 3]) -> (char, f64, Option<Color>)) {
+        // Project vertices to 2D FIRST
+        let projected_vertices: [Vec2; 3] = [
+            camera.project(t.vertices[0]),
+            camera.project(t.vertices[1]),
+            camera.project(t.vertices[2]),
+        ];
+        let depths = [t.vertices[0].z, t.vertices[1].z, t.vertices[2].z];
+
+        // Then rasterize in 2D screen space
+        let fragments = Triangle::rasterize_2d_triangle_with_depth(projected_vertices, depths, camera.projection().is_perspective());
+
+        fragments.iter().for_each(|f| {
+            let x = f.position.x as isize;
+            let y = f.position.y as isize;
+
+            if x >= 0
+                && y >= 0
+                && x < self.0.width() as isize
+                && y < self.0.height() as isize
+            {
+                let (value, intensity, color) = shade(f.weights);
+                self.0.write(x as usize, y as usize, f.depth, value, intensity, color);
+            }
+        })
+    }
 }
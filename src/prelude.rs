@@ -2,3 +2,4 @@ pub use super::engine::*;
 pub use crate::engine::math::*;
 pub use crate::engine::graphics::*;
 pub use crate::engine::rendering::*;
+pub use crate::engine::io::*;
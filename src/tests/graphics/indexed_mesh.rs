@@ -0,0 +1,63 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn indexed_mesh_shares_cube_vertices() {
+    let cube = MESHES::cube(2.0);
+
+    let indexed = IndexedMesh::from(&cube);
+
+    assert_eq!(indexed.vertices.len(), 8);
+    assert_eq!(indexed.indices.len(), 12);
+    assert_eq!(indexed.to_mesh(), cube);
+}
+
+#[test]
+pub fn indexed_mesh_welds_with_tolerance() {
+    let mesh = Mesh::new()
+        .add_triangle([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].into())
+        .add_triangle([[1.0001, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0001, 0.0]].into())
+        .build();
+
+    assert_eq!(IndexedMesh::from_mesh(&mesh, 1e-9).vertices.len(), 6);
+    assert_eq!(IndexedMesh::from_mesh(&mesh, 1e-3).vertices.len(), 4);
+}
+
+#[test]
+pub fn indexed_mesh_welds_exactly_and_far_from_origin() {
+    assert_eq!(IndexedMesh::from_mesh(&MESHES::cube(2.0), 0.0).vertices.len(), 8);
+    assert_eq!(IndexedMesh::from(&MESHES::cube(2.0e10)).vertices.len(), 8);
+    assert_eq!(IndexedMesh::from_mesh(&MESHES::cube(1.0e300), 1e-9).to_mesh(), MESHES::cube(1.0e300));
+}
+
+#[test]
+pub fn indexed_mesh_keeps_vertices_with_different_attributes() {
+    let up = [Vec3::new(0.0, 0.0, -1.0); 3];
+    let side = [Vec3::new(1.0, 0.0, 0.0); 3];
+    let mesh = Mesh::new()
+        .add_triangle(Triangle::from([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]).with_normals(up))
+        .add_triangle(Triangle::from([[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]).with_normals(side))
+        .build();
+
+    let indexed = IndexedMesh::from(&mesh);
+
+    assert_eq!(indexed.vertices.len(), 6);
+    assert_eq!(indexed.normals.as_ref().map(|n| n.len()), Some(6));
+    assert_eq!(indexed.triangle(1), mesh.triangles[1]);
+}
+
+#[test]
+pub fn indexed_mesh_computes_smooth_normals() {
+    let mut sphere = IndexedMesh::from(&MESHES::icosphere(2.0, 1));
+    sphere.compute_normals(NormalWeighting::Area);
+
+    sphere.vertices.iter().zip(sphere.normals.as_ref().unwrap()).for_each(|(v, n)| {
+        assert!(n.dot(v.normalize()) > 0.99);
+    });
+
+    let cube = IndexedMesh::from(&MESHES::cube(2.0));
+    let corner = cube.vertices.iter().position(|v| *v == Vec3::new(1.0, 1.0, 1.0)).unwrap();
+    let normal = cube.vertex_normals(NormalWeighting::Angle)[corner];
+
+    assert!(normal.distance(Vec3::new(1.0, 1.0, 1.0).normalize()) < 1e-9);
+}
@@ -0,0 +1,23 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn material_picks_chars_from_ramp() {
+    let default = Material::new();
+    let ramp = Material::new().with_ramp(" oO");
+    let fixed = Material::new().with_ramp(" oO").with_character('X');
+
+    assert_eq!(default.ascii(1.0), '@');
+    assert_eq!(ramp.ascii(0.0), ' ');
+    assert_eq!(ramp.ascii(0.5), 'o');
+    assert_eq!(ramp.ascii(2.0), 'O');
+    assert_eq!(fixed.ascii(0.0), 'X');
+}
+
+#[test]
+pub fn material_weights_illumination() {
+    let material = Material::new().with_ambient(0.5).with_diffuse(0.25).with_specular(2.0);
+    let illumination = Illumination { ambient: 0.2, diffuse: 0.8, specular: 0.1 };
+
+    assert!((material.intensity(&illumination) - 0.5).abs() < 1e-9);
+}
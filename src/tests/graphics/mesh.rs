@@ -13,7 +13,20 @@ pub fn mesh_init() {
         triangles: vec![[[1.0, 1.0, 1.0], [2.0, 2.0, 2.0], [3.0, 3.0, 3.0]].into()],
         position: [0.0, 0.0, 0.0].into(),
         rotation: Rotation::new(2.0, 0.0, 0.0),
+        material: Material::default(),
     };
 
     assert_eq!(mesh, expected);
 }
+
+#[test]
+pub fn mesh_world_matrix_moves_triangles() {
+    let mesh = Mesh::new()
+        .set_triangles(vec![[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].into()])
+        .set_position([0.0, 0.0, 10.0].into())
+        .build();
+
+    let moved = mesh.triangles[0].transform(&mesh.world_matrix());
+
+    assert_eq!(moved, [[0.0, 0.0, 10.0], [1.0, 0.0, 10.0], [0.0, 1.0, 10.0]].into());
+}
@@ -0,0 +1,79 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+#[allow(unused_imports)]
+use std::f64::consts::PI;
+
+#[test]
+pub fn cube_faces_point_outwards() {
+    let cube = MESHES::cube(2.0);
+
+    cube.triangles.iter().for_each(|t| {
+        let center = (t.vertices[0] + t.vertices[1] + t.vertices[2]).normalize();
+        assert!(t.normal().dot(center) > 0.0);
+    });
+}
+
+#[allow(dead_code)]
+fn signed_volume(mesh: &Mesh) -> f64 {
+    mesh.triangles
+        .iter()
+        .map(|t| t.vertices[0].dot(t.vertices[1].cross(t.vertices[2])) / 6.0)
+        .sum()
+}
+
+#[allow(dead_code)]
+fn assert_convex_outwards(mesh: &Mesh) {
+    mesh.triangles.iter().for_each(|t| {
+        let centroid = (t.vertices[0] + t.vertices[1] + t.vertices[2]) / 3.0;
+        assert!(t.normal().length() > 0.0);
+        assert!(t.normal().dot(centroid) > 0.0);
+    });
+}
+
+#[test]
+pub fn cube_is_centered_at_origin() {
+    let cube = MESHES::cube(2.0);
+
+    assert_eq!(cube.position, Vec3::new(0.0, 0.0, 0.0));
+    assert!((signed_volume(&cube) - 8.0).abs() < 1e-9);
+}
+
+#[test]
+pub fn convex_primitives_face_outwards() {
+    [
+        MESHES::uv_sphere(1.0, 16, 8),
+        MESHES::icosphere(1.0, 2),
+        MESHES::cylinder(1.0, 2.0, 12),
+        MESHES::cone(1.0, 2.0, 12),
+        MESHES::capsule(0.5, 3.0, 12, 4),
+        MESHES::pyramid(2.0, 1.0),
+    ]
+    .iter()
+    .for_each(assert_convex_outwards);
+}
+
+#[test]
+pub fn primitives_enclose_expected_volume() {
+    let close = |mesh: Mesh, expected: f64, tolerance: f64| {
+        let volume = signed_volume(&mesh);
+        assert!((volume - expected).abs() < expected * tolerance, "{volume} != {expected}");
+    };
+
+    close(MESHES::uv_sphere(1.0, 64, 32), 4.0 / 3.0 * PI, 0.02);
+    close(MESHES::icosphere(1.0, 3), 4.0 / 3.0 * PI, 0.02);
+    close(MESHES::cylinder(1.0, 2.0, 64), 2.0 * PI, 0.01);
+    close(MESHES::cone(1.0, 3.0, 64), PI, 0.01);
+    close(MESHES::torus(2.0, 0.5, 64, 32), 2.0 * PI * PI * 2.0 * 0.25, 0.02);
+    close(MESHES::pyramid(2.0, 3.0), 4.0, 1e-9);
+    close(MESHES::arrow(2.0, 0.1, 0.3, 0.5, 64), PI * (0.01 * 1.5 + 0.09 * 0.5 / 3.0), 0.01);
+}
+
+#[test]
+pub fn grid_faces_up() {
+    let grid = MESHES::grid(4.0, 2.0, 4, 2);
+
+    assert_eq!(grid.triangles.len(), 16);
+    grid.triangles.iter().for_each(|t| {
+        assert!(t.normal().normalize().dot(Vec3::new(0.0, 1.0, 0.0)) > 0.999);
+    });
+}
@@ -1,3 +1,7 @@
 pub(crate) mod vec3;
+pub(crate) mod vec2;
 pub(crate) mod triangle;
 pub(crate) mod mesh;
+pub(crate) mod meshes;
+pub(crate) mod indexed_mesh;
+pub(crate) mod material;
@@ -15,3 +15,16 @@ pub fn triangle_init() {
         [3.0, 3.1, 3.1]
     ].into())
 }
+
+#[test]
+pub fn triangle_rasterize_interpolates_depth() {
+    let vertices = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(0.0, 10.0)];
+
+    let fragments = Triangle::rasterize_2d_triangle_with_depth(vertices, [2.0, 4.0, 4.0], true);
+
+    assert!(!fragments.is_empty());
+    assert!(fragments.iter().all(|f| f.depth >= 2.0 - 1e-9 && f.depth <= 4.0 + 1e-9));
+
+    let corner = fragments.iter().find(|f| f.position.x == 0.0 && f.position.y == 0.0).unwrap();
+    assert!((corner.depth - 2.0).abs() < 1e-9);
+}
@@ -0,0 +1,33 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn vec2_ops() {
+    let mut vec = Vec2::new(1.0, -2.0);
+
+    assert_eq!(vec + Vec2::new(1.0, 1.0), Vec2::new(2.0, -1.0));
+    assert_eq!(vec - Vec2::new(1.0, 1.0), Vec2::new(0.0, -3.0));
+    assert_eq!(vec * 2.0, Vec2::new(2.0, -4.0));
+    assert_eq!(vec / 2.0, Vec2::new(0.5, -1.0));
+    assert_eq!(-vec, Vec2::new(-1.0, 2.0));
+
+    vec += Vec2::new(1.0, 0.0);
+    vec *= 3.0;
+    vec -= Vec2::new(0.0, 2.0);
+    vec /= 2.0;
+
+    assert_eq!(vec, Vec2::new(3.0, -4.0));
+}
+
+#[test]
+pub fn vec2_helpers() {
+    let a = Vec2::new(3.0, 4.0);
+
+    assert_eq!(a.length(), 5.0);
+    assert_eq!(a.try_normalize(), Some(Vec2::new(0.6, 0.8)));
+    assert_eq!(Vec2::new(0.0, 0.0).try_normalize(), None);
+    assert_eq!(a.lerp(Vec2::new(5.0, 6.0), 0.5), Vec2::new(4.0, 5.0));
+    assert_eq!(a.project(Vec2::new(2.0, 0.0)), Vec2::new(3.0, 0.0));
+    assert_eq!(Vec2::new(1.0, -1.0).reflect(Vec2::new(0.0, 1.0)), Vec2::new(1.0, 1.0));
+    assert_eq!(Vec2::from(Matrix([[1.0], [2.0]])), Vec2::new(1.0, 2.0));
+}
@@ -34,3 +34,52 @@ pub fn vec3_sub() {
 
     assert_eq!(result, [0.0, 0.0, 0.0].into());
 }
+
+#[test]
+pub fn vec3_scalar_ops() {
+    let mut vec: Vec3 = [1.0, -2.0, 3.0].into();
+
+    assert_eq!(vec * 2.0, [2.0, -4.0, 6.0].into());
+    assert_eq!(2.0 * vec, [2.0, -4.0, 6.0].into());
+    assert_eq!(vec / 2.0, [0.5, -1.0, 1.5].into());
+    assert_eq!(-vec, [-1.0, 2.0, -3.0].into());
+
+    vec += [1.0, 1.0, 1.0].into();
+    vec -= [0.0, 1.0, 0.0].into();
+    vec *= 2.0;
+    vec /= 4.0;
+
+    assert_eq!(vec, [1.0, -1.0, 2.0].into());
+}
+
+#[test]
+pub fn vec3_helpers() {
+    let a: Vec3 = [1.0, -2.0, 3.0].into();
+    let b: Vec3 = [3.0, 2.0, -1.0].into();
+
+    assert_eq!(a.component_mul(b), [3.0, -4.0, -3.0].into());
+    assert_eq!(a.lerp(b, 0.5), [2.0, 0.0, 1.0].into());
+    assert_eq!(Vec3::new(0.0, 0.0, 0.0).distance([3.0, 4.0, 0.0].into()), 5.0);
+    assert_eq!(Vec3::new(1.0, -1.0, 0.0).reflect([0.0, 1.0, 0.0].into()), [1.0, 1.0, 0.0].into());
+    assert_eq!(Vec3::new(2.0, 3.0, 0.0).project([1.0, 0.0, 0.0].into()), [2.0, 0.0, 0.0].into());
+    assert_eq!(a.min(b), [1.0, -2.0, -1.0].into());
+    assert_eq!(a.max(b), [3.0, 2.0, 3.0].into());
+    assert_eq!(a.abs(), [1.0, 2.0, 3.0].into());
+}
+
+#[test]
+pub fn vec3_try_normalize() {
+    assert_eq!(Vec3::new(0.0, 0.0, 0.0).try_normalize(), None);
+    assert_eq!(Vec3::new(0.0, 3.0, 0.0).try_normalize(), Some([0.0, 1.0, 0.0].into()));
+}
+
+#[test]
+pub fn vec3_conversions() {
+    let vec = Vec3::new(1.0, 2.0, 3.0);
+    let column: Matrix<f64, 3, 1> = vec.into();
+
+    assert_eq!(column, Matrix([[1.0], [2.0], [3.0]]));
+    assert_eq!(Vec3::from(column), vec);
+    assert_eq!(Vec2::from(vec), Vec2::new(1.0, 2.0));
+    assert_eq!(Vec3::from(Vec2::new(1.0, 2.0)), [1.0, 2.0, 0.0].into());
+}
@@ -0,0 +1,3 @@
+pub(crate) mod obj;
+pub(crate) mod stl;
+pub(crate) mod ply;
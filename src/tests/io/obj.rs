@@ -0,0 +1,63 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn obj_triangulates_polygons() {
+    let source = "
+        # square and a triangle
+        v 0 0 0
+        v 1 0 0
+        v 1 1 0
+        v 0 1 0
+        f 1 2 3 4
+        f -4 -3 -2
+    ";
+
+    let mesh = OBJ::parse_mesh(source).unwrap();
+
+    assert_eq!(mesh.triangles.len(), 3);
+    assert_eq!(mesh.triangles[1], [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]].into());
+    assert_eq!(mesh.triangles[2], [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]].into());
+}
+
+#[test]
+pub fn obj_reads_normals_uvs_and_groups() {
+    let source = "
+        v 0 0 0
+        v 1 0 0
+        v 0 1 0
+        vt 0 0
+        vt 1 0
+        vt 0 1
+        vn 0 0 -1
+        o first
+        f 1/1/1 2/2/1 3/3/1
+        g second
+        f 1//1 2//1 3//1
+    ";
+
+    let groups = OBJ::parse(source).unwrap();
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].object.as_deref(), Some("first"));
+    assert_eq!(groups[1].group.as_deref(), Some("second"));
+    assert_eq!(groups[1].object.as_deref(), Some("first"));
+
+    let first = &groups[0].mesh.triangles[0];
+    assert_eq!(first.uvs, Some([Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)]));
+    assert_eq!(first.normals, Some([Vec3::new(0.0, 0.0, -1.0); 3]));
+    assert_eq!(groups[1].mesh.triangles[0].uvs, None);
+}
+
+#[test]
+pub fn obj_reports_line_of_error() {
+    let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\n\nf 1 2 7\n";
+
+    match OBJ::parse(source) {
+        Err(ObjError::Parse { line, .. }) => assert_eq!(line, 5),
+        other => panic!("unexpected result {other:?}"),
+    }
+
+    assert!(matches!(OBJ::parse("v 0 zero 0"), Err(ObjError::Parse { line: 1, .. })));
+    assert!(matches!(OBJ::parse("v 0 0 0\nf 1 1"), Err(ObjError::Parse { line: 2, .. })));
+}
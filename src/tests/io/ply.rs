@@ -0,0 +1,101 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[cfg(test)]
+const HEADER: &str = "element vertex 4
+property float x
+property float y
+property float z
+property float nx
+property float ny
+property float nz
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+";
+
+#[cfg(test)]
+fn binary(big_endian: bool) -> Vec<u8> {
+    let format = if big_endian { "binary_big_endian" } else { "binary_little_endian" };
+    let mut bytes = format!("ply\nformat {format} 1.0\n{HEADER}").into_bytes();
+
+    for (i, p) in [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]].iter().enumerate() {
+        for c in p.iter().chain(&[0.0, 0.0, -1.0]) {
+            bytes.extend(if big_endian { c.to_be_bytes() } else { c.to_le_bytes() });
+        }
+        bytes.extend([i as u8 * 10, 20, 30]);
+    }
+
+    bytes.push(4);
+    for i in 0..4i32 {
+        bytes.extend(if big_endian { i.to_be_bytes() } else { i.to_le_bytes() });
+    }
+
+    bytes
+}
+
+#[test]
+pub fn ply_reads_ascii_with_attributes() {
+    let source = format!(
+        "ply\nformat ascii 1.0\ncomment made by hand\n{HEADER}0 0 0 0 0 -1 0 20 30\n1 0 0 0 0 -1 10 20 30\n1 1 0 0 0 -1 20 20 30\n0 1 0 0 0 -1 30 20 30\n4 0 1 2 3\n"
+    );
+
+    let mesh = PLY::read(source.as_bytes()).unwrap();
+
+    assert_eq!(mesh.triangles.len(), 2);
+    assert_eq!(mesh.triangles[1].vertices, [Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)]);
+    assert_eq!(mesh.triangles[0].normals, Some([Vec3::new(0.0, 0.0, -1.0); 3]));
+    assert_eq!(mesh.triangles[1].colors, Some([Color::new(0, 20, 30), Color::new(20, 20, 30), Color::new(30, 20, 30)]));
+}
+
+#[test]
+pub fn ply_reads_binary_in_both_endians() {
+    let little = PLY::read(&binary(false)).unwrap();
+    let big = PLY::read(&binary(true)).unwrap();
+
+    assert_eq!(little, big);
+    assert_eq!(little.triangles.len(), 2);
+    assert_eq!(little.triangles[0].colors.unwrap()[1], Color::new(10, 20, 30));
+    assert_eq!(little.triangles[0].normals, Some([Vec3::new(0.0, 0.0, -1.0); 3]));
+}
+
+#[test]
+pub fn ply_reports_errors() {
+    let mut truncated = binary(false);
+    truncated.truncate(truncated.len() - 3);
+
+    assert!(matches!(PLY::read(&truncated), Err(PlyError::Data { index: 0, .. })));
+    assert!(matches!(PLY::read(b"ply\nformat ascii 1.0\nelement vertex 1\n"), Err(PlyError::Header { line: 4, .. })));
+    assert!(matches!(PLY::read(b"obj\n"), Err(PlyError::Header { line: 1, .. })));
+    assert!(matches!(
+        PLY::read(format!("ply\nformat ascii 1.0\n{HEADER}0 0 0 0 0 1 0 0 0\n3 0 1 2\n").as_bytes()),
+        Err(PlyError::Data { .. })
+    ));
+}
+
+#[test]
+pub fn ply_rejects_invalid_vertex_indices() {
+    let invalid = |result: Result<Mesh, PlyError>| {
+        matches!(result, Err(PlyError::Data { element, message, .. }) if element == "face" && message.starts_with("invalid"))
+    };
+    let ascii = |face: &str| {
+        let source = format!(
+            "ply\nformat ascii 1.0\n{HEADER}0 0 0 0 0 -1 0 0 0\n1 0 0 0 0 -1 0 0 0\n1 1 0 0 0 -1 0 0 0\n0 1 0 0 0 -1 0 0 0\n{face}\n"
+        );
+        PLY::read(source.as_bytes())
+    };
+
+    assert!(invalid(ascii("4 -1 1 2 3")));
+    assert!(invalid(ascii("4 0 2.7 1 3")));
+    assert!(invalid(ascii("-4 0 1 2 3")));
+    assert!(invalid(ascii("2.5 0 1 2")));
+    assert!(ascii("4 0 1 2 3").is_ok());
+
+    let mut negative = binary(false);
+    let first_index = negative.len() - 16;
+    negative[first_index..first_index + 4].copy_from_slice(&(-1i32).to_le_bytes());
+    assert!(invalid(PLY::read(&negative)));
+}
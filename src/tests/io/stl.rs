@@ -0,0 +1,48 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn stl_binary_roundtrip() {
+    let cube = MESHES::cube(2.0);
+    let mut bytes = vec![];
+
+    STL::write_binary(&cube, &mut bytes).unwrap();
+    let loaded = STL::read(&bytes).unwrap();
+
+    assert_eq!(bytes.len(), 84 + 50 * cube.triangles.len());
+    assert_eq!(loaded.triangles, cube.triangles);
+}
+
+#[test]
+pub fn stl_ascii_roundtrip() {
+    let cube = MESHES::cube(2.0);
+    let mut bytes = vec![];
+
+    STL::write_ascii(&cube, &mut bytes).unwrap();
+    let source = String::from_utf8(bytes.clone()).unwrap();
+    let loaded = STL::read(&bytes).unwrap();
+
+    assert!(source.contains("facet normal 0e0 0e0 -1e0"));
+    assert_eq!(loaded.triangles, cube.triangles);
+}
+
+#[test]
+pub fn stl_rejects_truncated_binary() {
+    let mut bytes = vec![];
+    STL::write_binary(&MESHES::cube(1.0), &mut bytes).unwrap();
+    bytes.truncate(200);
+
+    assert!(matches!(STL::read_binary(&bytes), Err(StlError::Truncated { expected: 684, found: 200 })));
+    assert!(matches!(STL::read(&bytes[..20]), Err(StlError::Truncated { .. })));
+}
+
+#[test]
+pub fn stl_rejects_malformed_ascii() {
+    let missing_vertex = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nendloop\nendfacet\nendsolid a\n";
+    let bad_number = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 x 0\n";
+    let unfinished = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nvertex 0 1 0\nendloop\nendfacet\n";
+
+    assert!(matches!(STL::read_ascii(missing_vertex), Err(StlError::Parse { line: 6, .. })));
+    assert!(matches!(STL::read_ascii(bad_number), Err(StlError::Parse { line: 5, .. })));
+    assert!(matches!(STL::read_ascii(unfinished), Err(StlError::Parse { line: 8, .. })));
+}
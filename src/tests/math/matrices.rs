@@ -61,3 +61,82 @@ pub fn matrix_mul() {
             [139, 154]
     ]));
 }
+
+#[test]
+pub fn matrix_transpose() {
+    let matrix = Matrix([
+        [1, 2, 3],
+        [4, 5, 6]
+    ]);
+
+    assert_eq!(matrix.transpose(), Matrix([
+        [1, 4],
+        [2, 5],
+        [3, 6]
+    ]));
+}
+
+#[test]
+pub fn matrix_determinant() {
+    let matrix = Matrix([
+        [2.0, -3.0, 1.0],
+        [2.0, 0.0, -1.0],
+        [1.0, 4.0, 5.0]
+    ]);
+
+    assert!((matrix.determinant() - 49.0).abs() < 1e-9);
+    assert_eq!(Matrix::<f64, 4, 4>::identity().determinant(), 1.0);
+}
+
+#[test]
+pub fn matrix_inverse() {
+    use crate::prelude::*;
+
+    let matrix = model_matrix_3d(&Rotation::new(0.3, -1.2, 2.0), Vec3::new(1.0, 2.0, 3.0));
+    let product = matrix * matrix.inverse().unwrap();
+    let identity = Matrix::<f64, 4, 4>::identity();
+
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((product[(i, j)] - identity[(i, j)]).abs() < 1e-9);
+        }
+    }
+
+    assert_eq!(Matrix([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+}
+
+#[test]
+pub fn matrix_transforms_vec3() {
+    use crate::prelude::*;
+
+    let point = Vec3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Matrix::<f64, 3, 3>::identity() * point, point);
+    assert_eq!(translation_matrix_3d(Vec3::new(1.0, 1.0, 1.0)) * point, Vec3::new(2.0, 3.0, 4.0));
+    assert_eq!(scale_matrix_3d(Vec3::new(2.0, 2.0, 2.0)) * point, Vec3::new(2.0, 4.0, 6.0));
+}
+
+#[test]
+pub fn matrix_projections_map_into_unit_volume() {
+    use crate::prelude::*;
+
+    let perspective = perspective_matrix_3d(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    let near = perspective * Vec3::new(1.0, 1.0, 1.0);
+    let far = perspective * Vec3::new(0.0, 0.0, 10.0);
+
+    assert!((near.x - 1.0).abs() < 1e-9 && (near.y - 1.0).abs() < 1e-9 && near.z.abs() < 1e-9);
+    assert!((far.z - 1.0).abs() < 1e-9);
+
+    let orthographic = orthographic_matrix_3d(-2.0, 2.0, -1.0, 1.0, 0.0, 4.0);
+    assert_eq!(orthographic * Vec3::new(2.0, -1.0, 2.0), Vec3::new(1.0, -1.0, 0.5));
+}
+
+#[test]
+pub fn matrix_look_at_matches_camera_view() {
+    use crate::prelude::*;
+
+    let look_at = look_at_matrix_3d(Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+    let camera = Camera::new().location([0.0, 0.0, -5.0].into()).build();
+
+    assert_eq!(look_at, camera.view_matrix());
+}
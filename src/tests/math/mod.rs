@@ -1,2 +1,3 @@
 pub(crate) mod matrices;
 pub(crate) mod rotations;
+pub(crate) mod quaternion;
@@ -0,0 +1,80 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[allow(dead_code)]
+fn assert_matrix_eq(a: Matrix<f64, 3, 3>, b: Matrix<f64, 3, 3>) {
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((a[(i, j)] - b[(i, j)]).abs() < 1e-9, "{a:?} != {b:?}");
+        }
+    }
+}
+
+#[test]
+pub fn quaternion_matches_rotation_matrix() {
+    let rotation = Rotation::new(0.4, -1.1, 2.3);
+
+    let q = Quaternion::from_rotation(&rotation, EulerOrder::XYZ);
+
+    assert_matrix_eq(q.to_matrix(), rotation_matrix_3d(&rotation));
+}
+
+#[test]
+pub fn quaternion_euler_roundtrip_in_every_order() {
+    let orders = [EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX];
+
+    for order in orders {
+        for rotation in [Rotation::new(0.4, -1.1, 2.3), Rotation::new(0.7, 0.0, -0.2)] {
+            let q = Quaternion::from_rotation(&rotation, order);
+            let back = Quaternion::from_rotation(&q.to_rotation(order), order);
+
+            assert_matrix_eq(q.to_matrix(), back.to_matrix());
+        }
+    }
+}
+
+#[test]
+pub fn quaternion_euler_handles_gimbal_lock() {
+    let rotation = Rotation::new(0.3, std::f64::consts::FRAC_PI_2, 0.5);
+    let q = Quaternion::from_rotation(&rotation, EulerOrder::XYZ);
+
+    let back = Quaternion::from_rotation(&q.to_rotation(EulerOrder::XYZ), EulerOrder::XYZ);
+
+    assert_matrix_eq(q.to_matrix(), back.to_matrix());
+}
+
+#[test]
+pub fn quaternion_matrix_roundtrip() {
+    let q = Quaternion::from_axis_angle(Vec3::new(1.0, 2.0, -0.5), 2.9);
+
+    assert_matrix_eq(Quaternion::from_matrix(&q.to_matrix()).to_matrix(), q.to_matrix());
+}
+
+#[test]
+pub fn quaternion_composition_and_slerp() {
+    let quarter = Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_2);
+    let half = quarter * quarter;
+
+    let rotated = half.rotate(Vec3::new(1.0, 0.0, 0.0));
+    assert!((rotated.x + 1.0).abs() < 1e-9 && rotated.y.abs() < 1e-9);
+
+    let interpolated = Quaternion::identity().slerp(half, 0.5);
+    assert_matrix_eq(interpolated.to_matrix(), quarter.to_matrix());
+}
+
+#[test]
+pub fn quaternion_orients_mesh() {
+    let q = Quaternion::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 1.0);
+    let mesh = Mesh::new().set_orientation(q).build();
+
+    assert_matrix_eq(rotation_matrix_3d(&mesh.rotation), q.to_matrix());
+    assert_matrix_eq(mesh.orientation().to_matrix(), q.to_matrix());
+}
+
+#[test]
+pub fn quaternion_zero_axis_is_identity() {
+    let q = Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 0.0), 1.2);
+
+    assert_eq!(q, Quaternion::identity());
+    assert_eq!(q.rotate(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(1.0, 2.0, 3.0));
+}
@@ -1,4 +1,18 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
 #[test]
 pub fn rotate_x_point_3d() {
 
 }
+
+#[test]
+pub fn model_matrix_rotates_then_translates() {
+    let model = model_matrix_3d(&Rotation::new(0.0, 0.0, std::f64::consts::FRAC_PI_2), Vec3::new(10.0, 0.0, 5.0));
+
+    let result = model * Vec3::new(1.0, 0.0, 0.0);
+
+    assert!((result.x - 10.0).abs() < 1e-9);
+    assert!((result.y - 1.0).abs() < 1e-9);
+    assert!((result.z - 5.0).abs() < 1e-9);
+}
@@ -1,2 +1,4 @@
 pub(crate) mod math;
 pub(crate) mod graphics;
+pub(crate) mod rendering;
+pub(crate) mod io;
@@ -0,0 +1,54 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn ascii_buffer_depth_test_keeps_nearest() {
+    let mut buffer = AsciiBuffer::new(2, vec![' '; 4]);
+
+    assert!(buffer.update_at_depth(1, 1, 5.0, '#'));
+    assert!(!buffer.update_at_depth(1, 1, 8.0, '.'));
+    assert!(buffer.update_at_depth(1, 1, 2.0, '@'));
+
+    assert_eq!(buffer[(1, 1)], '@');
+    assert_eq!(buffer.depth[3], 2.0);
+}
+
+#[test]
+pub fn ascii_buffer_clear_resets_depth() {
+    let mut buffer = AsciiBuffer::new(2, vec![' '; 4]);
+    buffer.update_at_depth(0, 0, 1.0, '#');
+
+    buffer.clear();
+
+    assert_eq!(buffer.buffer, vec![' '; 4]);
+    assert!(buffer.depth.iter().all(|d| d.is_infinite()));
+}
+
+#[test]
+pub fn ascii_buffer_keeps_colors_of_nearest_char() {
+    let mut buffer = AsciiBuffer::new(2, vec![' '; 4]);
+    let red = Color::new(255, 0, 0);
+
+    buffer.update_colored_at_depth(0, 1, 5.0, '#', Some(red), Some(Color::default()));
+    buffer.update_colored_at_depth(0, 1, 8.0, '.', None, None);
+
+    assert_eq!(buffer.foreground[2], Some(red));
+    assert_eq!(buffer.background[2], Some(Color::default()));
+
+    buffer.clear();
+
+    assert!(buffer.foreground.iter().chain(&buffer.background).all(|c| c.is_none()));
+}
+
+#[test]
+pub fn ascii_buffer_presents_to_any_backend() {
+    let buffer = AsciiBuffer::new(2, vec!['a', 'b', 'c', 'd']);
+    let memory = MemoryBackend::new();
+    let mut terminal = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+
+    buffer.present_to(&mut memory.clone()).unwrap();
+    buffer.present_to(&mut terminal).unwrap();
+
+    assert_eq!(memory.lines(), vec!["ab", "cd"]);
+    assert_eq!(String::from_utf8(terminal.into_inner()).unwrap().matches(['a', 'b', 'c', 'd']).count(), 4);
+}
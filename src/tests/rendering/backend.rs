@@ -0,0 +1,59 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn writer_backend_writes_plain_text_frames() {
+    let mut backend = WriterBackend::new(Vec::new());
+    let mut frame = AsciiBuffer::new(3, vec![' '; 6]);
+    frame.foreground[0] = Some(Color::new(255, 0, 0));
+    frame[(0, 0)] = '#';
+
+    backend.present(&frame).unwrap();
+    frame[(2, 1)] = '@';
+    backend.present(&frame).unwrap();
+
+    assert_eq!(String::from_utf8(backend.into_inner()).unwrap(), "#  \n   \n\n#  \n  @\n\n");
+}
+
+#[test]
+pub fn memory_backend_shares_last_frame_between_clones() {
+    let backend = MemoryBackend::new();
+    let mut boxed: Box<dyn Backend> = Box::new(backend.clone());
+
+    boxed.present(&AsciiBuffer::new(2, vec!['a', 'b', 'c', 'd'])).unwrap();
+    boxed.present(&AsciiBuffer::new(2, vec!['e', 'f', 'g', 'h'])).unwrap();
+
+    assert_eq!(backend.text(), "ef\ngh");
+    assert_eq!(backend.frames(), 2);
+    assert_eq!(boxed.size(), None);
+}
+
+#[test]
+pub fn terminal_backend_writes_into_any_writer() {
+    let mut backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+    let frame = AsciiBuffer::new(2, vec!['x'; 4]);
+
+    backend.init().unwrap();
+    backend.present(&frame).unwrap();
+    backend.present(&frame).unwrap();
+    backend.restore().unwrap();
+
+    // second frame is the same, so nothing is printed again
+    let out = String::from_utf8(backend.into_inner()).unwrap();
+    assert_eq!(out.matches('x').count(), 4);
+}
+
+#[test]
+pub fn terminal_backend_on_writer_reports_only_given_size() {
+    let backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+    let sized = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome).with_size(40, 12);
+
+    assert_eq!(backend.size(), None);
+    assert_eq!(sized.size(), Some((40, 12)));
+
+    let mut renderer = Renderer::new().backend(sized).build();
+    let frame = renderer.render_to_string(&Camera::new().width(40).height(12).build());
+
+    assert_eq!(frame.lines().count(), 12);
+    assert!(frame.lines().all(|line| line.chars().count() == 40));
+}
@@ -0,0 +1,86 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn camera_view_matrix_moves_world_into_camera_space() {
+    let camera = Camera::new()
+        .location([1.0, 2.0, -5.0].into())
+        .build();
+
+    let result = camera.view_matrix() * Vec3::new(1.0, 2.0, 0.0);
+
+    assert_eq!(result, [0.0, 0.0, 5.0].into());
+}
+
+#[test]
+pub fn camera_view_matrix_inverts_camera_rotation() {
+    let mut camera = Camera::new().build();
+    camera.set_location([0.0, 0.0, 0.0].into());
+    camera.set_rotation(Rotation::new(0.0, std::f64::consts::FRAC_PI_2, 0.0));
+
+    // camera turned towards +X sees a point on the X axis straight ahead
+    let result = camera.view_matrix() * Vec3::new(3.0, 0.0, 0.0);
+
+    assert!(result.x.abs() < 1e-9);
+    assert!(result.y.abs() < 1e-9);
+    assert!((result.z - 3.0).abs() < 1e-9);
+}
+
+#[test]
+pub fn camera_fov_units() {
+    let camera = Camera::new().fov_degrees(90.0).build();
+
+    assert!((camera.fov() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    assert!((camera.fov_degrees() - 90.0).abs() < 1e-9);
+    assert_eq!(Camera::new().build().fov(), camera.fov());
+}
+
+#[test]
+pub fn camera_projection_corrects_char_aspect() {
+    let camera = Camera::new().width(80).height(40).fov_degrees(90.0).char_aspect(2.0).build();
+
+    let center = camera.project(Vec3::new(0.0, 0.0, 5.0));
+    let corner = camera.project(Vec3::new(1.0, 1.0, 5.0));
+
+    assert_eq!((center.x, center.y), (40.0, 20.0));
+    // the same distance spans twice as many columns as rows
+    assert!(((corner.x - center.x) - 2.0 * (corner.y - center.y)).abs() < 1e-9);
+    assert!((camera.aspect_ratio() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+pub fn camera_orthographic_ignores_distance() {
+    let camera = Camera::new().width(80).height(40).char_aspect(2.0).orthographic(10.0).build();
+
+    let near = camera.project(Vec3::new(1.0, 1.0, 2.0));
+    let far = camera.project(Vec3::new(1.0, 1.0, 200.0));
+
+    assert_eq!((near.x, near.y), (far.x, far.y));
+    // 40 rows cover 10 units
+    assert_eq!((near.x, near.y), (48.0, 24.0));
+}
+
+#[test]
+pub fn camera_orthographic_frustum_bounds_view_volume() {
+    let camera = Camera::new().width(80).height(40).char_aspect(2.0).orthographic(10.0).build();
+    let frustum = camera.frustum();
+
+    let inside: Triangle = [[-4.0, -4.0, 50.0], [4.0, -4.0, 50.0], [0.0, 4.0, 50.0]].into();
+    let outside: Triangle = [[6.0, 0.0, 50.0], [7.0, 0.0, 50.0], [6.0, 1.0, 50.0]].into();
+
+    assert!(!frustum.is_outside(&inside));
+    assert!(frustum.is_outside(&outside));
+}
+
+#[test]
+#[should_panic(expected = "view height")]
+pub fn camera_rejects_empty_orthographic_view() {
+    Camera::new().orthographic(0.0).build();
+}
+
+#[test]
+#[should_panic(expected = "view height")]
+pub fn camera_rejects_negative_orthographic_view() {
+    let mut camera = Camera::new().build();
+    camera.set_projection(Projection::Orthographic { view_height: -2.0 });
+}
@@ -0,0 +1,25 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+#[allow(unused_imports)]
+use crossterm::style;
+
+#[test]
+pub fn color_mode_detects_terminal_capabilities() {
+    assert_eq!(ColorMode::detect_from(Some("truecolor"), Some("xterm-256color"), false), ColorMode::TrueColor);
+    assert_eq!(ColorMode::detect_from(None, Some("xterm-256color"), false), ColorMode::Ansi256);
+    assert_eq!(ColorMode::detect_from(None, Some("xterm"), false), ColorMode::Ansi16);
+    assert_eq!(ColorMode::detect_from(None, Some("dumb"), false), ColorMode::Monochrome);
+    assert_eq!(ColorMode::detect_from(None, None, false), ColorMode::Monochrome);
+    assert_eq!(ColorMode::detect_from(Some("truecolor"), Some("xterm"), true), ColorMode::Monochrome);
+}
+
+#[test]
+pub fn color_mode_converts_to_closest_palette_color() {
+    let orange = Color::new(255, 135, 0);
+
+    assert_eq!(ColorMode::TrueColor.convert(orange), Some(style::Color::Rgb { r: 255, g: 135, b: 0 }));
+    assert_eq!(ColorMode::Ansi256.convert(orange), Some(style::Color::AnsiValue(208)));
+    assert_eq!(ColorMode::Ansi256.convert(Color::new(128, 128, 128)), Some(style::Color::AnsiValue(244)));
+    assert_eq!(ColorMode::Ansi16.convert(Color::new(250, 10, 10)), Some(style::Color::Red));
+    assert_eq!(ColorMode::Monochrome.convert(orange), None);
+}
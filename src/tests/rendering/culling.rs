@@ -0,0 +1,38 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn culling_back_faces_of_cube() {
+    let camera = Camera::new().location([0.0, 0.0, -10.0].into()).build();
+    let view = camera.view_matrix();
+    let cube = MESHES::cube(2.0);
+
+    let visible = cube
+        .triangles
+        .iter()
+        .filter(|t| !CullMode::Back.culls(&t.transform(&view), WindingOrder::CounterClockwise, &camera))
+        .count();
+
+    // only the face looking at the camera is left
+    assert_eq!(visible, 2);
+}
+
+#[test]
+pub fn culling_respects_mode_and_winding() {
+    let camera = Camera::new().build();
+    let triangle: Triangle = [[0.0, 0.0, 5.0], [0.0, 1.0, 5.0], [1.0, 0.0, 5.0]].into();
+
+    assert!(!CullMode::Back.culls(&triangle, WindingOrder::CounterClockwise, &camera));
+    assert!(CullMode::Front.culls(&triangle, WindingOrder::CounterClockwise, &camera));
+    assert!(CullMode::Back.culls(&triangle, WindingOrder::Clockwise, &camera));
+    assert!(!CullMode::None.culls(&triangle, WindingOrder::Clockwise, &camera));
+}
+
+#[test]
+pub fn culling_orthographic_uses_view_axis() {
+    let camera = Camera::new().orthographic(10.0).build();
+    // facing the camera, but seen from the side by a perspective camera at the origin
+    let triangle: Triangle = [[5.0, 0.0, 5.0], [5.0, 1.0, 5.0], [6.0, 0.0, 5.0]].into();
+
+    assert!(!CullMode::Back.culls(&triangle, WindingOrder::CounterClockwise, &camera));
+}
@@ -0,0 +1,49 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn frustum_clips_triangle_crossing_near_plane() {
+    let camera = Camera::new().near(1.0).build();
+    let triangle: Triangle = [[0.0, 0.0, 5.0], [1.0, 0.0, 5.0], [0.0, 0.0, -5.0]].into();
+
+    let clipped = camera.frustum().clip_triangle(&triangle);
+
+    assert!(!clipped.is_empty());
+    assert!(clipped.iter().all(|t| t.vertices.iter().all(|v| v.z >= 1.0 - 1e-9)));
+}
+
+#[test]
+pub fn frustum_culls_triangle_behind_camera() {
+    let camera = Camera::new().build();
+    let triangle: Triangle = [[0.0, 0.0, -5.0], [1.0, 0.0, -5.0], [0.0, 1.0, -5.0]].into();
+
+    assert!(camera.frustum().is_outside(&triangle));
+    assert!(camera.frustum().clip_triangle(&triangle).is_empty());
+}
+
+#[test]
+pub fn frustum_keeps_visible_triangle_intact() {
+    let camera = Camera::new().build();
+    let triangle: Triangle = [[0.0, 0.0, 5.0], [1.0, 0.0, 5.0], [0.0, 1.0, 5.0]].into();
+
+    assert_eq!(camera.frustum().clip_triangle(&triangle), vec![triangle]);
+}
+
+#[test]
+pub fn frustum_clipping_blends_attributes() {
+    let camera = Camera::new().near(1.0).build();
+    let up = Vec3::new(0.0, -1.0, 0.0);
+    let side = Vec3::new(1.0, 0.0, 0.0);
+    let triangle = Triangle::from([[0.0, 0.0, 5.0], [1.0, 0.0, 5.0], [0.0, 0.0, -3.0]])
+        .with_normals([up, up, side]);
+
+    let clipped = camera.frustum().clip_triangle(&triangle);
+    let cut = clipped
+        .iter()
+        .flat_map(|t| t.vertices.iter().zip(t.normals.unwrap()))
+        .find(|(v, _)| (v.z - 1.0).abs() < 1e-9 && v.x.abs() < 1e-9)
+        .unwrap();
+
+    // halfway between the first and the last corner
+    assert!(cut.1.distance((up + side).normalize()) < 1e-9);
+}
@@ -0,0 +1,64 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[allow(dead_code)]
+const UP: Vec3 = Vec3 { x: 0.0, y: -1.0, z: 0.0 };
+
+#[test]
+pub fn directional_light_follows_lambert() {
+    let light = Light::directional(Vec3::new(1.0, -1.0, 0.0), 1.0);
+    let lit = light.illuminate(Vec3::new(0.0, 0.0, 0.0), UP, UP, 32.0);
+
+    assert!((lit.diffuse - 0.5_f64.sqrt()).abs() < 1e-9);
+    assert_eq!(light.illuminate(Vec3::new(0.0, 0.0, 0.0), -UP, UP, 32.0), Illumination::default());
+}
+
+#[test]
+pub fn point_light_attenuates_with_distance() {
+    let light = Light::point(Vec3::new(0.0, -2.0, 0.0), 1.0).with_attenuation(Attenuation::new(1.0, 0.0, 1.0));
+
+    let near = light.illuminate(Vec3::new(0.0, -1.0, 0.0), UP, UP, 32.0);
+    let far = light.illuminate(Vec3::new(0.0, 0.0, 0.0), UP, UP, 32.0);
+
+    assert!((near.diffuse - 0.5).abs() < 1e-9);
+    assert!((far.diffuse - 0.2).abs() < 1e-9);
+}
+
+#[test]
+pub fn spot_light_fades_outside_cone() {
+    let light = Light::spot(Vec3::new(0.0, -1.0, 0.0), -UP, 0.2, 0.4, 1.0).with_attenuation(Attenuation::none());
+
+    let center = light.illuminate(Vec3::new(0.0, 0.0, 0.0), UP, UP, 32.0);
+    let edge = light.illuminate(Vec3::new(0.3_f64.tan(), 0.0, 0.0), UP, UP, 32.0);
+    let outside = light.illuminate(Vec3::new(1.0, 0.0, 0.0), UP, UP, 32.0);
+
+    assert!((center.diffuse - 1.0).abs() < 1e-9);
+    assert!(edge.diffuse > 0.0 && edge.diffuse < 0.3_f64.cos());
+    assert_eq!(outside, Illumination::default());
+}
+
+#[test]
+pub fn specular_highlight_peaks_at_mirror_direction() {
+    let light = Light::directional(Vec3::new(1.0, -1.0, 0.0), 1.0);
+    let point = Vec3::new(0.0, 0.0, 0.0);
+
+    let mirror = light.illuminate(point, UP, Vec3::new(-1.0, -1.0, 0.0).normalize(), 32.0);
+    let off = light.illuminate(point, UP, Vec3::new(1.0, -1.0, 0.0).normalize(), 32.0);
+
+    assert!((mirror.specular - 1.0).abs() < 1e-9);
+    assert!(off.specular < mirror.specular);
+}
+
+#[test]
+pub fn light_without_direction_lights_nothing() {
+    let zero = Vec3::new(0.0, 0.0, 0.0);
+    let lights = [
+        Light::directional(zero, 1.0),
+        Light::spot(Vec3::new(0.0, -1.0, 0.0), zero, 0.2, 2.0, 1.0),
+        Light::directional(zero, 1.0).transform(&Matrix::<f64, 4, 4>::from(rotation_matrix_3d(&Rotation::new(0.3, 0.2, 0.1)))),
+    ];
+
+    for light in lights {
+        assert_eq!(light.illuminate(Vec3::new(0.0, 0.0, 0.0), UP, UP, 32.0), Illumination::default());
+    }
+}
@@ -0,0 +1,13 @@
+pub(crate) mod camera;
+pub(crate) mod ascii_buffer;
+pub(crate) mod culling;
+pub(crate) mod frustum;
+pub(crate) mod transferer;
+pub(crate) mod light;
+pub(crate) mod color_mode;
+pub(crate) mod pixel_buffer;
+pub(crate) mod presenter;
+pub(crate) mod backend;
+pub(crate) mod renderer;
+pub(crate) mod render_loop;
+pub(crate) mod terminal_session;
@@ -0,0 +1,57 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn pixel_buffer_encodes_half_blocks() {
+    let red = Color::new(255, 0, 0);
+    let blue = Color::new(0, 0, 255);
+    let mut pixels = PixelBuffer::new(3, 2);
+    pixels.write(0, 0, 1.0, '@', 1.0, Some(red));
+    pixels.write(1, 1, 1.0, '@', 1.0, Some(blue));
+    pixels.write(2, 0, 1.0, '@', 1.0, Some(red));
+    pixels.write(2, 1, 2.0, '@', 1.0, Some(blue));
+
+    let mut cells = AsciiBuffer::new(3, vec![' '; 3]);
+    pixels.encode(RenderMode::HalfBlock, &mut cells);
+
+    assert_eq!(cells.buffer, vec!['▀', '▄', '▀']);
+    assert_eq!(cells.foreground, vec![Some(red), Some(blue), Some(red)]);
+    assert_eq!(cells.background, vec![None, None, Some(blue)]);
+    assert_eq!(cells.depth[2], 1.0);
+}
+
+#[test]
+pub fn pixel_buffer_encodes_braille_dots() {
+    let mut pixels = PixelBuffer::new(2, 4);
+    pixels.write(0, 0, 1.0, '@', 1.0, None);
+    pixels.write(1, 3, 1.0, '@', 1.0, None);
+    // too dark to light a dot
+    pixels.write(1, 0, 1.0, '.', 0.0, None);
+
+    let mut cells = AsciiBuffer::new(1, vec![' '; 1]);
+    pixels.encode(RenderMode::Braille, &mut cells);
+
+    assert_eq!(cells[(0, 0)], char::from_u32(0x2800 + 0x01 + 0x80).unwrap());
+    assert_eq!(cells.foreground[0], Some(Color::new(255, 255, 255)));
+}
+
+#[test]
+pub fn transferer_renders_more_pixels_than_cells() {
+    let camera = Camera::new().width(40).height(20).build();
+    let mut scene = Scene::new();
+    scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0));
+    scene.add_mesh(Mesh::new().set_triangles(MESHES::cube(2.0).triangles).set_position([0.0, 0.0, 5.0].into()).build());
+
+    let mut cells = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+    Transferer(&mut cells, &scene).start_transfering(&camera, &TransferOptions::default());
+
+    let braille = camera.scaled(2, 4);
+    let mut pixels = PixelBuffer::new(braille.width, braille.height);
+    Transferer(&mut pixels, &scene).start_transfering(&braille, &TransferOptions::default());
+
+    let lit_cells = cells.depth.iter().filter(|d| d.is_finite()).count();
+    let lit_pixels = pixels.depth.iter().filter(|d| d.is_finite()).count();
+
+    assert!(lit_pixels > lit_cells * 6);
+    assert_eq!(braille.project(Vec3::new(1.0, -1.0, 5.0)).x, camera.project(Vec3::new(1.0, -1.0, 5.0)).x * 2.0);
+}
@@ -0,0 +1,89 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[allow(dead_code)]
+fn cursor_moves(out: &[u8]) -> usize {
+    // MoveTo is emitted as ESC [ row ; column H
+    String::from_utf8_lossy(out).matches('H').count()
+}
+
+#[test]
+pub fn presenter_writes_only_changed_runs() {
+    let mut presenter = Presenter::new(ColorMode::Monochrome);
+    let mut frame = AsciiBuffer::new(8, vec![' '; 16]);
+    presenter.present(&frame, &mut Vec::new()).unwrap();
+
+    frame[(1, 0)] = '#';
+    frame[(2, 0)] = '#';
+    frame[(3, 0)] = '#';
+    frame[(6, 1)] = '@';
+
+    let mut out = Vec::new();
+    assert_eq!(presenter.present(&frame, &mut out).unwrap(), 4);
+    assert_eq!(cursor_moves(&out), 2);
+    assert!(String::from_utf8_lossy(&out).contains("###"));
+
+    let mut out = Vec::new();
+    assert_eq!(presenter.present(&frame, &mut out).unwrap(), 0);
+    assert_eq!(cursor_moves(&out), 0);
+}
+
+#[test]
+pub fn presenter_redraws_on_color_change_and_request() {
+    let mut presenter = Presenter::new(ColorMode::TrueColor);
+    let mut frame = AsciiBuffer::new(4, vec!['#'; 4]);
+    presenter.present(&frame, &mut Vec::new()).unwrap();
+
+    frame.foreground[3] = Some(Color::new(255, 0, 0));
+    assert_eq!(presenter.present(&frame, &mut Vec::new()).unwrap(), 1);
+
+    presenter.invalidate();
+    assert_eq!(presenter.present(&frame, &mut Vec::new()).unwrap(), 4);
+
+    let mut always = Presenter::new(ColorMode::Monochrome).with_full_redraw(true);
+    always.present(&frame, &mut Vec::new()).unwrap();
+    assert_eq!(always.present(&frame, &mut Vec::new()).unwrap(), 4);
+}
+
+#[test]
+pub fn presenter_clears_screen_after_resize() {
+    let cleared = |out: &[u8]| String::from_utf8_lossy(out).contains("\x1b[2J");
+    let mut presenter = Presenter::new(ColorMode::Monochrome);
+
+    let mut out = Vec::new();
+    presenter.present(&AsciiBuffer::new(4, vec!['#'; 8]), &mut out).unwrap();
+    assert!(!cleared(&out));
+
+    let mut out = Vec::new();
+    assert_eq!(presenter.present(&AsciiBuffer::new(2, vec!['#'; 4]), &mut out).unwrap(), 4);
+    assert!(cleared(&out));
+
+    let mut out = Vec::new();
+    presenter.present(&AsciiBuffer::new(2, vec!['#'; 4]), &mut out).unwrap();
+    assert!(!cleared(&out));
+
+    presenter.invalidate();
+    let mut out = Vec::new();
+    assert_eq!(presenter.present(&AsciiBuffer::new(2, vec!['#'; 4]), &mut out).unwrap(), 4);
+    assert!(cleared(&out));
+}
+
+#[test]
+pub fn presenter_writes_nothing_for_empty_frame() {
+    let mut presenter = Presenter::new(ColorMode::Monochrome);
+
+    assert_eq!(presenter.present(&AsciiBuffer::new(0, vec![]), &mut Vec::new()).unwrap(), 0);
+    assert_eq!(presenter.present(&AsciiBuffer::new(0, vec![' '; 4]), &mut Vec::new()).unwrap(), 0);
+
+    let mut renderer = Renderer::new().height(24.0).backend(TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome)).build();
+    let mut updates = 0;
+    let stats = renderer.run(&mut Camera::new().build(), |_, _, _| {
+        updates += 1;
+
+        match updates {
+            2 => LoopControl::Stop,
+            _ => LoopControl::Continue,
+        }
+    });
+    assert_eq!(stats.frames, 1);
+}
@@ -0,0 +1,116 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[allow(dead_code)]
+fn renderer(backend: &MemoryBackend) -> Renderer {
+    let mut renderer = Renderer::new().width(20.0).height(10.0).fps(0).backend(backend.clone()).build();
+    renderer.add_mesh(MESHES::cube(2.0));
+
+    renderer
+}
+
+#[test]
+pub fn render_loop_stops_from_update_callback() {
+    let backend = MemoryBackend::new();
+    let mut renderer = renderer(&backend);
+    let mut camera = Camera::new().width(20).height(10).location([0.0, 0.0, -5.0].into()).build();
+    let mut updates = 0;
+
+    let stats = renderer.run(&mut camera, |scene, camera, _| {
+        updates += 1;
+        scene.meshes[0].rotation.y += 0.1;
+        camera.set_location([0.0, 0.0, -6.0].into());
+
+        match updates {
+            4 => LoopControl::Stop,
+            _ => LoopControl::Continue,
+        }
+    });
+
+    assert_eq!(stats.frames, 3);
+    assert_eq!(backend.frames(), 3);
+    assert_eq!(camera.location(), Vec3::new(0.0, 0.0, -6.0));
+    assert!(stats.max_frame_time >= stats.average_frame_time);
+}
+
+#[test]
+pub fn render_loop_stops_and_pauses_through_handle() {
+    let backend = MemoryBackend::new();
+    let mut renderer = renderer(&backend);
+    let handle = renderer.handle();
+    let mut camera = Camera::new().width(20).height(10).location([0.0, 0.0, -5.0].into()).build();
+
+    let stats = renderer.run(&mut camera, |_, _, _| {
+        match (backend.frames(), handle.is_paused()) {
+            (2, false) => {
+                handle.pause();
+                // loop keeps sleeping while paused, resume from another thread
+                let remote = handle.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(20));
+                    remote.resume();
+                });
+            }
+            (5, _) => handle.stop(),
+            _ => {}
+        }
+
+        LoopControl::Continue
+    });
+
+    assert_eq!(stats.frames, 6);
+    assert_eq!(renderer.stats().frames, 6);
+}
+
+#[test]
+pub fn render_loop_honours_stop_before_start() {
+    let backend = MemoryBackend::new();
+    let mut renderer = renderer(&backend);
+    let mut camera = Camera::new().width(20).height(10).location([0.0, 0.0, -5.0].into()).build();
+
+    renderer.handle().stop();
+    let stopped = renderer.run(&mut camera, |_, _, _| LoopControl::Continue);
+
+    assert_eq!(stopped.frames, 0);
+    assert_eq!(backend.frames(), 0);
+    assert!(!renderer.handle().is_stopped());
+
+    let resumed = renderer.run(&mut camera, |_, _, _| match backend.frames() {
+        2 => LoopControl::Stop,
+        _ => LoopControl::Continue,
+    });
+
+    assert_eq!(resumed.frames, 2);
+}
+
+#[test]
+pub fn render_loop_draws_nothing_when_backend_fails_to_initialize() {
+    type Calls = std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>;
+    struct Failing(Calls);
+
+    impl Backend for Failing {
+        fn init(&mut self) -> std::io::Result<()> {
+            Err(std::io::Error::other("no terminal"))
+        }
+
+        fn present(&mut self, _: &AsciiBuffer) -> std::io::Result<()> {
+            self.0.borrow_mut().push("present");
+            Ok(())
+        }
+
+        fn restore(&mut self) -> std::io::Result<()> {
+            self.0.borrow_mut().push("restore");
+            Ok(())
+        }
+    }
+
+    let calls = Calls::default();
+    let mut renderer = Renderer::new().width(20.0).height(10.0).fps(0).backend(Failing(calls.clone())).build();
+    let errors = calls.clone();
+    renderer.on_error(move |message| errors.borrow_mut().push(message));
+
+    let stats = renderer.run(&mut Camera::new().build(), |_, _, _| LoopControl::Stop);
+
+    assert_eq!(stats.frames, 0);
+    assert_eq!(*calls.borrow(), vec!["Failed to initialize the output"]);
+}
@@ -0,0 +1,55 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn renderer_renders_single_frame_headless() {
+    let backend = MemoryBackend::new();
+    let mut renderer = Renderer::new().width(20.0).height(10.0).backend(backend.clone()).build();
+    renderer.add_mesh(Mesh::new().set_triangles(MESHES::cube(2.0).triangles).set_position([0.0, 0.0, 5.0].into()).build());
+    let camera = Camera::new().width(20).height(10).build();
+
+    let frame = renderer.render_frame(&camera);
+
+    assert_eq!(frame.len(), 200);
+    assert_eq!(frame[(10, 5)], '@');
+    assert_eq!(frame[(0, 0)], ' ');
+    // nothing is shown on the backend
+    assert_eq!(backend.frames(), 0);
+}
+
+#[test]
+pub fn scene_renders_to_string_snapshot() {
+    let mut scene = Scene::new();
+    scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0));
+    scene.add_mesh(Mesh::new().set_triangles(MESHES::cube(2.0).triangles).set_position([0.0, 0.0, 6.0].into()).build());
+    let camera = Camera::new().width(12).height(6).build();
+
+    let expected = [
+        "            ",
+        "            ",
+        "     @@@    ",
+        "     @@@    ",
+        "     @@@    ",
+        "            ",
+    ]
+    .join("\n");
+
+    assert_eq!(scene.render(&camera).to_string(), expected);
+}
+
+#[test]
+pub fn renderer_light_direction_replaces_main_light() {
+    let build = |builder: RendererBuilder| builder.width(4.0).height(2.0).backend(MemoryBackend::new()).build();
+    let left = Vec3::new(-1.0, 0.0, 0.0);
+    let right = Vec3::new(1.0, 0.0, 0.0);
+
+    let replaced = build(Renderer::new().light_direction(left).light_direction(right));
+    let added = build(Renderer::new().light_direction(right).light(Light::ambient(0.2)));
+    let only_added = build(Renderer::new().light(Light::ambient(0.2)));
+    let default = build(Renderer::new());
+
+    assert_eq!(replaced.scene().lights, vec![Light::directional(right, 1.0)]);
+    assert_eq!(added.scene().lights, vec![Light::directional(right, 1.0), Light::ambient(0.2)]);
+    assert_eq!(only_added.scene().lights, vec![Light::ambient(0.2)]);
+    assert_eq!(default.scene().lights, vec![Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0)]);
+}
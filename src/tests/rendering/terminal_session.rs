@@ -0,0 +1,42 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+#[allow(unused_imports)]
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+#[test]
+pub fn terminal_session_recognizes_interrupt_keys() {
+    let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+
+    assert!(TerminalSession::is_interrupt(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+    assert!(TerminalSession::is_interrupt(&key(KeyCode::Char('d'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)));
+    assert!(!TerminalSession::is_interrupt(&key(KeyCode::Char('c'), KeyModifiers::NONE)));
+    assert!(!TerminalSession::is_interrupt(&key(KeyCode::Esc, KeyModifiers::CONTROL)));
+}
+
+#[test]
+pub fn terminal_backend_on_writer_leaves_process_terminal_alone() {
+    let mut backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+
+    backend.init().unwrap();
+
+    assert!(!TerminalSession::is_active());
+    assert!(!backend.quit_requested());
+    backend.restore().unwrap();
+}
+
+#[test]
+pub fn terminal_backend_restores_only_after_init() {
+    let mut backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+    backend.restore().unwrap();
+    assert!(backend.into_inner().is_empty());
+
+    let mut backend = TerminalBackend::with_writer(Vec::new(), ColorMode::Monochrome);
+    backend.init().unwrap();
+    backend.restore().unwrap();
+    backend.restore().unwrap();
+
+    // cursor is hidden once and shown once
+    let out = String::from_utf8(backend.into_inner()).unwrap();
+    assert_eq!(out.matches("\x1b[?25l").count(), 1);
+    assert_eq!(out.matches("\x1b[?25h").count(), 1);
+}
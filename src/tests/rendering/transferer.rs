@@ -0,0 +1,116 @@
+#[allow(unused_imports)]
+use crate::prelude::*;
+
+#[test]
+pub fn transferer_draws_nearest_mesh_on_top() {
+    let camera = Camera::new().width(40).height(20).build();
+    let mut buffer = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+
+    let mut near = MESHES::cube(2.0);
+    near.position = [0.0, 0.0, 5.0].into();
+    let mut far = MESHES::cube(2.0);
+    far.position = [0.0, 0.0, 8.0].into();
+
+    // far mesh is submitted last but must not overwrite the near one
+    let scene = Scene {
+        meshes: vec![IndexedMesh::from(&near), IndexedMesh::from(&far)],
+        lights: vec![Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0)],
+    };
+    Transferer(&mut buffer, &scene).start_transfering(&camera, &TransferOptions::default());
+
+    let center = buffer.depth[20 + 10 * 40];
+    assert!((center - 4.0).abs() < 1e-6);
+    assert_eq!(buffer[(20, 10)], '@');
+}
+
+#[test]
+pub fn transferer_smooth_shading_varies_across_faces() {
+    let camera = Camera::new().width(40).height(20).build();
+    let mut sphere = IndexedMesh::from(&MESHES::icosphere(2.0, 0));
+    sphere.position = [0.0, 0.0, 6.0].into();
+    sphere.compute_normals(NormalWeighting::Area);
+    let scene = Scene { meshes: vec![sphere], lights: vec![Light::directional(Vec3::new(0.3, -0.5, -1.0), 1.0)] };
+
+    let distinct_chars = |shading_mode: ShadingMode| {
+        let mut buffer = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+        let options = TransferOptions { shading_mode, ..Default::default() };
+        Transferer(&mut buffer, &scene).start_transfering(&camera, &options);
+
+        let mut chars = buffer.buffer.clone();
+        chars.sort();
+        chars.dedup();
+        chars.len()
+    };
+
+    assert!(distinct_chars(ShadingMode::Gouraud) > distinct_chars(ShadingMode::Flat));
+    assert!(distinct_chars(ShadingMode::Phong) > distinct_chars(ShadingMode::Flat));
+}
+
+#[test]
+pub fn transferer_adds_up_lights() {
+    let camera = Camera::new().width(40).height(20).build();
+    let mut scene = Scene::new();
+    scene.add_mesh(MESHES::cube(2.0));
+    scene.meshes[0].position = [0.0, 0.0, 5.0].into();
+
+    let center_char = |scene: &Scene| {
+        let mut buffer = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+        Transferer(&mut buffer, scene).start_transfering(&camera, &TransferOptions::default());
+        buffer[(20, 10)]
+    };
+
+    scene.lights = vec![Light::ambient(0.3)];
+    let ambient = center_char(&scene);
+    scene.add_light(Light::point(Vec3::new(0.0, 0.0, 0.0), 0.4));
+
+    assert_eq!(ambient, ascii_from_intensity(0.3));
+    assert!(ASCII_BRIGHTNESS.iter().position(|c| *c as char == center_char(&scene)) > ASCII_BRIGHTNESS.iter().position(|c| *c as char == ambient));
+}
+
+#[test]
+pub fn transferer_draws_meshes_with_their_material() {
+    let camera = Camera::new().width(40).height(20).build();
+    let mut scene = Scene::new();
+    scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 1.0));
+    scene.add_mesh(Mesh::new()
+        .set_triangles(MESHES::cube(2.0).triangles)
+        .set_position([-3.0, 0.0, 8.0].into())
+        .set_material(Material::new().with_character('A'))
+        .build());
+    scene.add_mesh(Mesh::new()
+        .set_triangles(MESHES::cube(2.0).triangles)
+        .set_position([3.0, 0.0, 8.0].into())
+        .set_material(Material::new().with_ramp(" xX"))
+        .build());
+
+    let mut buffer = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+    Transferer(&mut buffer, &scene).start_transfering(&camera, &TransferOptions::default());
+
+    assert!(buffer.buffer.contains(&'A'));
+    assert!(buffer.buffer.contains(&'X'));
+    assert!(!buffer.buffer.contains(&'@'));
+}
+
+#[test]
+pub fn transferer_shades_material_color_by_light() {
+    let camera = Camera::new().width(40).height(20).build();
+    let center = |material: Material| {
+        let mut scene = Scene::new();
+        scene.add_light(Light::directional(Vec3::new(0.0, 0.0, -1.0), 0.5));
+        scene.add_mesh(Mesh::new()
+            .set_triangles(MESHES::cube(2.0).triangles)
+            .set_position([0.0, 0.0, 5.0].into())
+            .set_material(material.with_color(Color::new(200, 100, 0)))
+            .build());
+
+        let mut buffer = AsciiBuffer::new(40, vec![' '; 40 * 20]);
+        Transferer(&mut buffer, &scene).start_transfering(&camera, &TransferOptions::default());
+        assert_eq!(buffer.foreground[0], None);
+
+        buffer.foreground[20 + 10 * 40].unwrap()
+    };
+
+    assert_eq!(center(Material::new().with_specular(0.0)), Color::new(100, 50, 0));
+    // Default materials add a highlight where the surface faces both the light and the viewer
+    assert!(center(Material::new()).r > 100);
+}